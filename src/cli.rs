@@ -0,0 +1,160 @@
+use crate::color::ColorMode;
+use crate::logo;
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+// Redrawing faster than this only burns CPU
+const MIN_WATCH_INTERVAL: f64 = 0.1;
+
+const USAGE: &str = "\
+Usage: meowfetch [options]
+       meowfetch logo convert <image> [--width <n>]
+
+Options:
+  -t, --type <n>    show built-in cat number n
+      --logo <name|path>
+                    show a logo from ~/.config/meowfetch/logos, a
+                    built-in cat (cat1, cat2, ...) or a logo file
+      --list-logos  show every logo by name
+      --json        print the collected information as JSON
+      --color <when>
+                    auto, always or never (default auto)
+      --redact      hide user, host, addresses and mount points
+      --watch [s]   redraw in place every s seconds (default 2)
+      --modules <list>
+                    comma separated modules to show, e.g. sys,cpu,mem
+  -h, --help        show this help
+
+Commands:
+  logo convert      print a PNG or PPM image as a half-block logo,
+                    --width cells wide (default 20)";
+
+// Logo width `logo convert` uses unless told otherwise
+const DEFAULT_LOGO_WIDTH: usize = 20;
+
+// Options given on the command line, unset options defer to the config
+#[derive(Debug, Default)]
+pub struct Cli {
+    pub cat_type: Option<usize>,
+    pub logo: Option<String>,
+    pub json: bool,
+    pub redact: bool,
+    pub color: ColorMode,
+    pub watch: Option<Duration>,
+    pub modules: Option<Vec<String>>,
+    pub command: Option<Command>,
+}
+
+// Subcommands that do something other than printing the fetch
+#[derive(Debug)]
+pub enum Command {
+    ConvertLogo { image: PathBuf, width: usize },
+    ListLogos,
+}
+
+impl Cli {
+    pub fn parse() -> Cli {
+        let mut cli = Cli::default();
+        let mut args = env::args().skip(1).peekable();
+        if args.peek().map(String::as_str) == Some("logo") {
+            args.next();
+            cli.command = Some(parse_logo_command(args));
+            return cli;
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-t" | "--type" => match args.next().and_then(|n| n.parse().ok()) {
+                    Some(n) if (1..=logo::BUILTIN_COUNT).contains(&n) => cli.cat_type = Some(n),
+                    _ => usage_error(&format!(
+                        "`--type` needs a cat number from 1 to {}",
+                        logo::BUILTIN_COUNT
+                    )),
+                },
+                "--logo" => match args.next() {
+                    Some(logo) => cli.logo = Some(logo),
+                    None => usage_error("`--logo` needs a name or a path"),
+                },
+                "--list-logos" => cli.command = Some(Command::ListLogos),
+                "--json" => cli.json = true,
+                "--redact" => cli.redact = true,
+                "--color" => cli.color = color_mode(args.next().as_deref()),
+                _ if arg.starts_with("--color=") => {
+                    cli.color = color_mode(arg.strip_prefix("--color="));
+                }
+                "--watch" => {
+                    // The interval is optional, only take the next argument if it is a number
+                    let seconds = match args.peek().and_then(|next| next.parse::<f64>().ok()) {
+                        Some(seconds) => {
+                            args.next();
+                            seconds
+                        }
+                        None => 2.0,
+                    };
+                    if !seconds.is_finite() || seconds <= 0.0 {
+                        usage_error("`--watch` needs a positive interval");
+                    }
+                    match Duration::try_from_secs_f64(seconds.max(MIN_WATCH_INTERVAL)) {
+                        Ok(interval) => cli.watch = Some(interval),
+                        Err(_) => usage_error("`--watch` interval is too long"),
+                    }
+                }
+                "--modules" => match args.next() {
+                    Some(list) => {
+                        cli.modules = Some(
+                            list.split(',')
+                                .map(|name| name.trim().to_string())
+                                .collect(),
+                        )
+                    }
+                    None => usage_error("`--modules` needs a list of module names"),
+                },
+                "-h" | "--help" => {
+                    println!("{}", USAGE);
+                    process::exit(0);
+                }
+                _ => usage_error(&format!("unknown argument `{}`", arg)),
+            }
+        }
+
+        cli
+    }
+}
+
+fn parse_logo_command(mut args: impl Iterator<Item = String>) -> Command {
+    if args.next().as_deref() != Some("convert") {
+        usage_error("`logo` needs a subcommand, e.g. `logo convert image.png`");
+    }
+
+    let mut image = None;
+    let mut width = DEFAULT_LOGO_WIDTH;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-w" | "--width" => match args.next().and_then(|n| n.parse().ok()) {
+                Some(n) if n > 0 => width = n,
+                _ => usage_error("`--width` needs a positive number"),
+            },
+            _ if image.is_none() && !arg.starts_with('-') => image = Some(PathBuf::from(arg)),
+            _ => usage_error(&format!("unknown argument `{}`", arg)),
+        }
+    }
+
+    match image {
+        Some(image) => Command::ConvertLogo { image, width },
+        None => usage_error("`logo convert` needs an image"),
+    }
+}
+
+fn color_mode(value: Option<&str>) -> ColorMode {
+    match value.and_then(ColorMode::parse) {
+        Some(mode) => mode,
+        None => usage_error("`--color` needs auto, always or never"),
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("meowfetch: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
@@ -0,0 +1,459 @@
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+
+/// How many colors the terminal can show, ordered from none to 24-bit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorLevel {
+    None,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+/// `--color=auto|always|never`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    pub fn parse(value: &str) -> Option<ColorMode> {
+        match value {
+            "auto" => Some(ColorMode::Auto),
+            "always" => Some(ColorMode::Always),
+            "never" => Some(ColorMode::Never),
+            _ => None,
+        }
+    }
+}
+
+/// Color level to render with, `NO_COLOR` and pipes only matter in auto mode
+pub fn detect(mode: ColorMode) -> ColorLevel {
+    match mode {
+        ColorMode::Never => ColorLevel::None,
+        ColorMode::Always => terminal_level().max(ColorLevel::Ansi16),
+        ColorMode::Auto => {
+            let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            if no_color || !io::stdout().is_terminal() {
+                ColorLevel::None
+            } else {
+                terminal_level()
+            }
+        }
+    }
+}
+
+// What the terminal advertises through COLORTERM, TERM and its terminfo entry
+fn terminal_level() -> ColorLevel {
+    if let Ok(colorterm) = env::var("COLORTERM") {
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            return ColorLevel::TrueColor;
+        }
+    }
+
+    let term = match env::var("TERM") {
+        Ok(term) if !term.is_empty() => term,
+        _ => return ColorLevel::None,
+    };
+    if term == "dumb" {
+        return ColorLevel::None;
+    }
+    if term.ends_with("-direct") || term.contains("truecolor") || term.contains("24bit") {
+        return ColorLevel::TrueColor;
+    }
+    if term == "linux" {
+        return ColorLevel::Ansi16;
+    }
+
+    match max_colors(&term) {
+        Some(colors) if colors >= 1 << 24 => ColorLevel::TrueColor,
+        Some(colors) if colors >= 256 => ColorLevel::Ansi256,
+        Some(_) => ColorLevel::Ansi16,
+        None if term.contains("256color") => ColorLevel::Ansi256,
+        None => ColorLevel::Ansi16,
+    }
+}
+
+// Where ncurses looks for compiled terminfo entries
+fn terminfo_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os("TERMINFO") {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".terminfo"));
+    }
+    if let Ok(list) = env::var("TERMINFO_DIRS") {
+        dirs.extend(
+            list.split(':')
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from),
+        );
+    }
+    for dir in ["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo"] {
+        dirs.push(PathBuf::from(dir));
+    }
+    dirs
+}
+
+// The `colors` number capability of a compiled terminfo entry
+fn max_colors(term: &str) -> Option<i32> {
+    let first = term.chars().next()?;
+    let data = terminfo_dirs().iter().find_map(|dir| {
+        fs::read(dir.join(first.to_string()).join(term))
+            .or_else(|_| fs::read(dir.join(format!("{:x}", first as u32)).join(term)))
+            .ok()
+    })?;
+    terminfo_colors(&data)
+}
+
+fn terminfo_colors(data: &[u8]) -> Option<i32> {
+    let short = |offset: usize| -> Option<i32> {
+        let bytes = data.get(offset..offset + 2)?;
+        Some(i16::from_le_bytes([bytes[0], bytes[1]]) as i32)
+    };
+    // Section sizes, a corrupt file can make them negative
+    let size = |offset: usize| -> Option<usize> { usize::try_from(short(offset)?).ok() };
+
+    // Legacy entries store numbers as 16 bits, the extended format as 32 bits
+    let number_size = match short(0)? {
+        0o432 => 2,
+        0o1036 => 4,
+        _ => return None,
+    };
+    let names_size = size(2)?;
+    let bools_count = size(4)?;
+    let numbers_count = size(6)?;
+
+    // `colors` is number capability 13
+    const COLORS: usize = 13;
+    if numbers_count <= COLORS {
+        return None;
+    }
+
+    // Header, names, booleans, then a pad byte to reach an even offset
+    let offset = names_size.checked_add(bools_count)?.checked_add(12)?;
+    let offset = (offset + offset % 2).checked_add(COLORS * number_size)?;
+    let bytes = data.get(offset..offset.checked_add(number_size)?)?;
+    let colors = match number_size {
+        2 => i16::from_le_bytes([bytes[0], bytes[1]]) as i32,
+        _ => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+    };
+    (colors > 0).then_some(colors)
+}
+
+/// Rewrite SGR color escapes in `text` so they fit `level`
+///
+/// 24-bit colors become the nearest 256 or 16 color, 256 colors become the
+/// nearest of the 16, and with no colors at all every escape sequence is removed.
+pub fn downgrade(text: &str, level: ColorLevel) -> String {
+    match level {
+        ColorLevel::TrueColor => return text.to_string(),
+        ColorLevel::None => return crate::width::strip_ansi(text),
+        _ => {}
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("\x1b[") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = match after.find(|c: char| ('@'..='~').contains(&c)) {
+            Some(end) => end,
+            None => {
+                result.push_str(&rest[start..]);
+                return result;
+            }
+        };
+        if after.as_bytes()[end] == b'm' {
+            result.push_str(&downgrade_sgr(&after[..end], level));
+        } else {
+            result.push_str(&rest[start..start + 2 + end + 1]);
+        }
+        rest = &after[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+fn downgrade_sgr(params: &str, level: ColorLevel) -> String {
+    let params: Vec<&str> = params.split(';').collect();
+    let number = |index: usize| -> Option<u8> { params.get(index)?.parse().ok() };
+
+    let mut codes = Vec::new();
+    let mut i = 0;
+    while i < params.len() {
+        let base = match params[i] {
+            "38" => 30,
+            "48" => 40,
+            code => {
+                codes.push(code.to_string());
+                i += 1;
+                continue;
+            }
+        };
+        let color = match params.get(i + 1).copied() {
+            Some("2") => match (number(i + 2), number(i + 3), number(i + 4)) {
+                (Some(r), Some(g), Some(b)) => {
+                    i += 5;
+                    Some(Color::Rgb(r, g, b))
+                }
+                _ => None,
+            },
+            Some("5") => number(i + 2).map(|index| {
+                i += 3;
+                Color::Indexed(index)
+            }),
+            _ => None,
+        };
+        match color {
+            Some(color) => codes.push(color.code(base, level)),
+            // Malformed, pass the rest through untouched
+            None => {
+                codes.extend(params[i..].iter().map(|param| param.to_string()));
+                break;
+            }
+        }
+    }
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// Run every 24-bit color of the SGR escapes in `text` through `map`
+pub fn recolor(text: &str, map: impl Fn((u8, u8, u8)) -> (u8, u8, u8)) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("\x1b[") {
+        result.push_str(&rest[..start + 2]);
+        rest = &rest[start + 2..];
+        let end = match rest.find('m') {
+            Some(end) if !rest[..end].contains('\x1b') => end,
+            _ => continue,
+        };
+
+        let mut params: Vec<String> = rest[..end].split(';').map(str::to_string).collect();
+        let mut i = 0;
+        while i + 4 < params.len() {
+            if (params[i] == "38" || params[i] == "48") && params[i + 1] == "2" {
+                let channel = |offset: usize| params[i + offset].parse::<u8>().ok();
+                if let (Some(r), Some(g), Some(b)) = (channel(2), channel(3), channel(4)) {
+                    let (r, g, b) = map((r, g, b));
+                    params[i + 2] = r.to_string();
+                    params[i + 3] = g.to_string();
+                    params[i + 4] = b.to_string();
+                }
+                i += 5;
+            } else {
+                i += 1;
+            }
+        }
+        result.push_str(&params.join(";"));
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+enum Color {
+    Rgb(u8, u8, u8),
+    Indexed(u8),
+}
+
+impl Color {
+    // SGR parameters for this color, `base` is 30 for foreground and 40 for background
+    fn code(&self, base: u8, level: ColorLevel) -> String {
+        match (self, level) {
+            (Color::Rgb(r, g, b), ColorLevel::Ansi256) => {
+                format!("{};5;{}", base + 8, nearest_256(*r, *g, *b))
+            }
+            (Color::Indexed(index), ColorLevel::Ansi256) => format!("{};5;{}", base + 8, index),
+            (Color::Rgb(r, g, b), _) => ansi16_code(base, nearest_16(*r, *g, *b)),
+            (Color::Indexed(index), _) => {
+                let (r, g, b) = ansi256_rgb(*index);
+                ansi16_code(base, nearest_16(r, g, b))
+            }
+        }
+    }
+}
+
+// 30-37 and 40-47 for the normal colors, 90-97 and 100-107 for the bright ones
+fn ansi16_code(base: u8, index: u8) -> String {
+    if index < 8 {
+        (base + index).to_string()
+    } else {
+        (base + 60 + index - 8).to_string()
+    }
+}
+
+// xterm's default values for the 16 colors
+const ANSI16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+// Levels of each channel in the 6x6x6 cube
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn ansi256_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize],
+        16..=231 => {
+            let index = index - 16;
+            (
+                CUBE[(index / 36) as usize],
+                CUBE[(index / 6 % 6) as usize],
+                CUBE[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+// Closest entry of the color cube or the grayscale ramp, the 16 theme colors are skipped
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    (16..=255)
+        .min_by_key(|index| distance((r, g, b), ansi256_rgb(*index)))
+        .unwrap_or(16)
+}
+
+fn nearest_16(r: u8, g: u8, b: u8) -> u8 {
+    (0..16)
+        .min_by_key(|index| distance((r, g, b), ANSI16[*index as usize]))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A compiled terminfo entry with 15 numbers, `colors` set to `colors`
+    fn terminfo(magic: i16, number_size: usize, colors: i32) -> Vec<u8> {
+        let names = b"test-256color|test terminal\0";
+        // An odd number of booleans, so the numbers need the pad byte
+        let bools = [1u8, 0, 1];
+        let mut data = Vec::new();
+        for short in [magic, names.len() as i16, bools.len() as i16, 15, 0, 0] {
+            data.extend_from_slice(&short.to_le_bytes());
+        }
+        data.extend_from_slice(names);
+        data.extend_from_slice(&bools);
+        if data.len() % 2 == 1 {
+            data.push(0);
+        }
+        for index in 0..15 {
+            let number = if index == 13 { colors } else { -1 };
+            match number_size {
+                2 => data.extend_from_slice(&(number as i16).to_le_bytes()),
+                _ => data.extend_from_slice(&number.to_le_bytes()),
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn reads_colors_from_legacy_terminfo() {
+        assert_eq!(terminfo_colors(&terminfo(0o432, 2, 256)), Some(256));
+        assert_eq!(terminfo_colors(&terminfo(0o432, 2, 8)), Some(8));
+    }
+
+    #[test]
+    fn reads_colors_from_extended_terminfo() {
+        assert_eq!(
+            terminfo_colors(&terminfo(0o1036, 4, 0x1000000)),
+            Some(0x1000000)
+        );
+    }
+
+    #[test]
+    fn rejects_broken_terminfo() {
+        assert_eq!(terminfo_colors(&terminfo(0o432, 2, -1)), None);
+        assert_eq!(terminfo_colors(&terminfo(0o777, 2, 256)), None);
+        let truncated = terminfo(0o432, 2, 256);
+        assert_eq!(terminfo_colors(&truncated[..truncated.len() - 6]), None);
+    }
+
+    #[test]
+    fn rejects_corrupt_terminfo_header() {
+        let valid = terminfo(0o432, 2, 256);
+        assert_eq!(terminfo_colors(&valid[..5]), None);
+        assert_eq!(terminfo_colors(&[]), None);
+        // Negative names, booleans and numbers sizes
+        for offset in [2, 4, 6] {
+            let mut corrupt = valid.clone();
+            corrupt[offset..offset + 2].copy_from_slice(&(-2i16).to_le_bytes());
+            assert_eq!(terminfo_colors(&corrupt), None);
+        }
+        // Sizes pointing far past the end of the file
+        let mut corrupt = valid;
+        corrupt[2..4].copy_from_slice(&i16::MAX.to_le_bytes());
+        assert_eq!(terminfo_colors(&corrupt), None);
+    }
+
+    #[test]
+    fn downgrades_truecolor_to_256() {
+        assert_eq!(
+            downgrade_sgr("38;2;255;0;0", ColorLevel::Ansi256),
+            "\x1b[38;5;196m"
+        );
+        assert_eq!(
+            downgrade_sgr("1;48;2;0;0;0;38;2;255;255;255", ColorLevel::Ansi256),
+            "\x1b[1;48;5;16;38;5;231m"
+        );
+    }
+
+    #[test]
+    fn downgrades_truecolor_and_256_to_16() {
+        assert_eq!(
+            downgrade_sgr("38;2;250;0;0", ColorLevel::Ansi16),
+            "\x1b[91m"
+        );
+        assert_eq!(downgrade_sgr("48;2;0;0;0", ColorLevel::Ansi16), "\x1b[40m");
+        assert_eq!(downgrade_sgr("38;5;21", ColorLevel::Ansi16), "\x1b[34m");
+    }
+
+    #[test]
+    fn passes_malformed_sgr_through() {
+        assert_eq!(
+            downgrade_sgr("38;2;300;0", ColorLevel::Ansi16),
+            "\x1b[38;2;300;0m"
+        );
+        assert_eq!(downgrade_sgr("1;38", ColorLevel::Ansi256), "\x1b[1;38m");
+        assert_eq!(downgrade_sgr("0", ColorLevel::Ansi16), "\x1b[0m");
+    }
+
+    #[test]
+    fn downgrade_keeps_other_escapes() {
+        let text = "\x1b[2K\x1b[38;2;255;0;0mcat\x1b[0m";
+        assert_eq!(
+            downgrade(text, ColorLevel::Ansi256),
+            "\x1b[2K\x1b[38;5;196mcat\x1b[0m"
+        );
+        assert_eq!(downgrade(text, ColorLevel::None), "cat");
+    }
+}
@@ -0,0 +1,455 @@
+use colored::Color;
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Top level of `~/.config/meowfetch/config.toml`
+///
+/// Every section is optional, missing keys fall back to the defaults below so
+/// an empty file behaves exactly like no config at all.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub logo: LogoConfig,
+    pub modules: ModulesConfig,
+    pub colors: ColorsConfig,
+    pub layout: LayoutConfig,
+    pub units: UnitsConfig,
+    pub disk: DiskConfig,
+    pub network: NetworkConfig,
+    pub temp: TempConfig,
+    pub palette: PaletteConfig,
+    pub bar: BarConfig,
+    /// Extra modules that show the output of a shell command, `[[custom]]` tables
+    pub custom: Vec<CustomConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogoConfig {
+    /// Built-in cat art, same values as the `-t` flag
+    #[serde(rename = "type")]
+    pub kind: Option<usize>,
+    /// Logo file in the `.meowrc` format (escape sequences written as `\x1b`)
+    pub path: Option<PathBuf>,
+    /// Logo from `~/.config/meowfetch/logos` or a built-in cat, by name
+    pub name: Option<String>,
+    /// PNG or PPM image shown instead of a text logo
+    pub image: Option<PathBuf>,
+    pub protocol: ImageProtocol,
+    /// Width of an image logo in cells
+    pub width: usize,
+    /// Distribution whose colors the default cat wears, detected from
+    /// os-release when unset, "none" keeps the original colors
+    pub distro: Option<String>,
+}
+
+impl Default for LogoConfig {
+    fn default() -> Self {
+        LogoConfig {
+            kind: None,
+            path: None,
+            name: None,
+            image: None,
+            protocol: ImageProtocol::Auto,
+            width: 20,
+            distro: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageProtocol {
+    /// Whatever the terminal supports, half blocks if it has no graphics
+    Auto,
+    Kitty,
+    Sixel,
+    /// Always convert the image to half blocks
+    Blocks,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ModulesConfig {
+    /// Info lines to show, top to bottom
+    pub order: Vec<String>,
+    /// What a module with nothing to report shows, e.g. `swap` without swap
+    pub unavailable: Unavailable,
+}
+
+impl Default for ModulesConfig {
+    fn default() -> Self {
+        let order = [
+            "title", "sys", "kernel", "uptime", "pkgs", "shell", "term", "cpu", "gpu", "temp",
+            "mem", "swap", "ipv4", "disk", "bat", "palette",
+        ];
+        ModulesConfig {
+            order: order.iter().map(|name| name.to_string()).collect(),
+            unavailable: Unavailable::Hide,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Unavailable {
+    /// Leave the line out
+    Hide,
+    /// Keep the label and show `none`
+    None,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColorsConfig {
+    /// Color of the `user@host` line
+    pub title: String,
+    /// Color of the labels in front of every info line
+    pub label: String,
+}
+
+impl Default for ColorsConfig {
+    fn default() -> Self {
+        ColorsConfig {
+            title: "bright green".to_string(),
+            label: "bright blue".to_string(),
+        }
+    }
+}
+
+impl ColorsConfig {
+    pub fn title_color(&self) -> Color {
+        parse_color(&self.title, Color::BrightGreen)
+    }
+
+    pub fn label_color(&self) -> Color {
+        parse_color(&self.label, Color::BrightBlue)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LayoutConfig {
+    /// Character repeated under the `user@host` line
+    pub separator: String,
+    /// Spaces between the logo and the info column
+    pub gap: usize,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        LayoutConfig {
+            separator: "━".to_string(),
+            gap: 0,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UnitsConfig {
+    pub system: UnitSystem,
+    /// "auto" picks the unit per value, or one fixed unit such as "GiB" or "MB"
+    pub unit: String,
+    /// Digits after the decimal point for memory, swap and disk sizes
+    pub precision: usize,
+}
+
+impl Default for UnitsConfig {
+    fn default() -> Self {
+        UnitsConfig {
+            system: UnitSystem::Iec,
+            unit: "auto".to_string(),
+            precision: 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitSystem {
+    /// Powers of 1024: KiB, MiB, GiB, TiB
+    Iec,
+    /// Powers of 1000: kB, MB, GB, TB
+    Si,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiskSort {
+    /// Keep the order the system lists mounts in
+    None,
+    /// Fullest disk first
+    Usage,
+    /// Biggest disk first
+    Size,
+    /// Alphabetical by mount point
+    Mount,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiskConfig {
+    /// Only show these file systems, empty means all
+    pub include_fs: Vec<String>,
+    /// Never show these file systems
+    pub exclude_fs: Vec<String>,
+    /// Only show mount points matching one of these globs, empty means all
+    pub include_mounts: Vec<String>,
+    /// Never show mount points matching one of these globs
+    pub exclude_mounts: Vec<String>,
+    /// Show a device mounted several times (bind mounts) only once
+    pub dedupe: bool,
+    pub sort: DiskSort,
+    /// Maximum number of disks, 0 shows all of them
+    pub limit: usize,
+}
+
+impl Default for DiskConfig {
+    fn default() -> Self {
+        let exclude_fs = [
+            "squashfs", "overlay", "tmpfs", "devtmpfs", "ramfs", "efivarfs",
+        ];
+        DiskConfig {
+            include_fs: Vec::new(),
+            exclude_fs: exclude_fs.iter().map(|fs| fs.to_string()).collect(),
+            include_mounts: Vec::new(),
+            exclude_mounts: Vec::new(),
+            dedupe: true,
+            sort: DiskSort::None,
+            limit: 5,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// Only show interfaces matching one of these globs, empty means all
+    pub include: Vec<String>,
+    /// Never show interfaces matching one of these globs, e.g. "veth*"
+    pub exclude: Vec<String>,
+    /// Only show the interfaces that carry a default route
+    pub default_route_only: bool,
+    /// Also list link-local addresses (fe80::/10) on the ipv6 line
+    pub link_local: bool,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        NetworkConfig {
+            include: Vec::new(),
+            exclude: vec!["*VMware*".to_string()],
+            default_route_only: false,
+            link_local: false,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TempConfig {
+    /// Only show sensors whose label matches one of these globs, empty means all
+    pub sensors: Vec<String>,
+    /// Threshold for sensors that do not report their own critical temperature
+    pub critical: f64,
+}
+
+impl Default for TempConfig {
+    fn default() -> Self {
+        // CPU package, GPU edge and NVMe composite temperatures
+        let sensors = [
+            "coretemp Package*",
+            "k10temp Tctl*",
+            "zenpower Tdie*",
+            "amdgpu edge*",
+            "nouveau*",
+            "nvme Composite*",
+        ];
+        TempConfig {
+            sensors: sensors.iter().map(|sensor| sensor.to_string()).collect(),
+            critical: 100.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PaletteRow {
+    /// The eight bright colors
+    Bright,
+    /// The eight normal colors
+    Dark,
+    /// All 16 ANSI colors in one row, in index order
+    #[serde(rename = "16")]
+    Ansi16,
+    /// The 6x6x6 color cube and the grayscale ramp of the 256 color palette
+    #[serde(rename = "256")]
+    Ansi256,
+    /// A 24-bit hue gradient
+    Truecolor,
+    /// The actual colors of the terminal theme as hex codes, asked for with OSC 4
+    Theme,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaletteConfig {
+    /// Character a color block is drawn with
+    pub glyph: String,
+    /// Glyphs per color block
+    pub width: usize,
+    pub rows: Vec<PaletteRow>,
+}
+
+impl Default for PaletteConfig {
+    fn default() -> Self {
+        PaletteConfig {
+            glyph: "█".to_string(),
+            width: 3,
+            rows: vec![PaletteRow::Bright, PaletteRow::Dark],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BarConfig {
+    /// Modules that draw a bar in front of their ratio, e.g. ["mem", "swap", "disk"]
+    pub modules: Vec<String>,
+    /// Cells between the brackets
+    pub width: usize,
+    pub fill: String,
+    pub empty: String,
+    /// Color every cell by its own position instead of the whole bar by its value
+    pub gradient: bool,
+    /// Colors below 50%, from 50% and from 90%
+    pub colors: [String; 3],
+}
+
+impl Default for BarConfig {
+    fn default() -> Self {
+        BarConfig {
+            modules: Vec::new(),
+            width: 10,
+            fill: "█".to_string(),
+            empty: "░".to_string(),
+            gradient: false,
+            colors: ["green".to_string(), "yellow".to_string(), "red".to_string()],
+        }
+    }
+}
+
+impl BarConfig {
+    pub fn colors(&self) -> [Color; 3] {
+        [
+            parse_color(&self.colors[0], Color::Green),
+            parse_color(&self.colors[1], Color::Yellow),
+            parse_color(&self.colors[2], Color::Red),
+        ]
+    }
+}
+
+// Unlike the other sections a custom module cannot do without its name and
+// command, so those two have no default
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomConfig {
+    /// Name used in `modules.order` and `--modules`
+    pub name: String,
+    /// Label in front of the output, the name when unset
+    pub label: Option<String>,
+    /// Run with `sh -c`, the first line it prints becomes the value
+    pub command: String,
+    /// Seconds to wait for the command before giving up on it
+    #[serde(default = "default_custom_timeout")]
+    pub timeout: f64,
+    /// Seconds to reuse the last output for, 0 runs the command every time
+    #[serde(default)]
+    pub ttl: u64,
+    /// Color of the output, names or `#rrggbb`
+    pub color: Option<String>,
+}
+
+fn default_custom_timeout() -> f64 {
+    1.0
+}
+
+impl CustomConfig {
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
+    pub fn color(&self) -> Option<Color> {
+        Some(parse_color(self.color.as_deref()?, Color::White))
+    }
+}
+
+// Accept both the names `colored` knows ("bright blue") and `#rrggbb`
+fn parse_color(name: &str, fallback: Color) -> Color {
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() == 6 {
+            if let Ok(rgb) = u32::from_str_radix(hex, 16) {
+                return Color::TrueColor {
+                    r: (rgb >> 16) as u8,
+                    g: (rgb >> 8) as u8,
+                    b: rgb as u8,
+                };
+            }
+        }
+        return fallback;
+    }
+    name.parse().unwrap_or(fallback)
+}
+
+/// Expand a leading `~/` the way a shell would
+pub fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+pub fn config_dir() -> Option<PathBuf> {
+    Some(dirs::home_dir()?.join(".config").join("meowfetch"))
+}
+
+/// A config file that exists but cannot be parsed
+#[derive(Debug)]
+pub struct ConfigError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl Config {
+    /// Load the config file, no file at all gives the defaults
+    pub fn load() -> Result<Config, ConfigError> {
+        let path = match config_dir() {
+            Some(dir) => dir.join("config.toml"),
+            None => return Ok(Config::default()),
+        };
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => {
+                return Err(ConfigError {
+                    path,
+                    message: err.to_string(),
+                })
+            }
+        };
+        toml::from_str(&content).map_err(|err| ConfigError {
+            path,
+            message: err.to_string(),
+        })
+    }
+}
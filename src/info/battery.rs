@@ -0,0 +1,113 @@
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Serialize)]
+pub struct BatteryInfo {
+    pub name: String,
+    pub capacity: Option<f64>,
+    pub status: Option<String>,
+    pub seconds_remaining: Option<u64>,
+    pub health: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PowerInfo {
+    pub batteries: Vec<BatteryInfo>,
+    pub ac_online: Option<bool>,
+}
+
+const POWER_SUPPLY: &str = "/sys/class/power_supply";
+
+/// Batteries and chargers from /sys/class/power_supply
+pub fn power() -> PowerInfo {
+    let mut power = PowerInfo {
+        batteries: Vec::new(),
+        ac_online: None,
+    };
+    let entries = match fs::read_dir(POWER_SUPPLY) {
+        Ok(entries) => entries,
+        Err(_) => return power,
+    };
+
+    let mut supplies: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    supplies.sort();
+
+    for path in supplies {
+        match read(&path, "type").as_deref() {
+            Some("Battery") => {
+                // Batteries of mice and keyboards report the `Device` scope
+                if read(&path, "scope").as_deref() == Some("Device") {
+                    continue;
+                }
+                power.batteries.push(battery(&path));
+            }
+            Some("Mains") | Some("USB") => {
+                if let Some(online) = read_number(&path, "online") {
+                    power.ac_online = Some(power.ac_online.unwrap_or(false) || online > 0.0);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    power
+}
+
+fn battery(path: &Path) -> BatteryInfo {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let status = read(path, "status");
+
+    // Drivers report either energy (µWh, µW) or charge (µAh, µA)
+    let (now, full, design, rate) = match read_number(path, "energy_now") {
+        Some(energy_now) => (
+            Some(energy_now),
+            read_number(path, "energy_full"),
+            read_number(path, "energy_full_design"),
+            read_number(path, "power_now"),
+        ),
+        None => (
+            read_number(path, "charge_now"),
+            read_number(path, "charge_full"),
+            read_number(path, "charge_full_design"),
+            read_number(path, "current_now"),
+        ),
+    };
+
+    let capacity = read_number(path, "capacity").or(match (now, full) {
+        (Some(now), Some(full)) if full > 0.0 => Some(now / full * 100.0),
+        _ => None,
+    });
+    let health = match (full, design) {
+        (Some(full), Some(design)) if design > 0.0 => Some(full / design * 100.0),
+        _ => None,
+    };
+
+    // Some drivers report a negative rate while discharging
+    let rate = rate.map(f64::abs).filter(|rate| *rate > 0.0);
+    let hours_remaining = match (status.as_deref(), now, full, rate) {
+        (Some("Discharging"), Some(now), _, Some(rate)) => Some(now / rate),
+        (Some("Charging"), Some(now), Some(full), Some(rate)) => Some((full - now).max(0.0) / rate),
+        _ => None,
+    };
+
+    BatteryInfo {
+        name,
+        capacity,
+        status,
+        seconds_remaining: hours_remaining.map(|hours| (hours * 3600.0) as u64),
+        health,
+    }
+}
+
+fn read(path: &Path, attribute: &str) -> Option<String> {
+    let value = fs::read_to_string(path.join(attribute)).ok()?;
+    Some(value.trim().to_string())
+}
+
+fn read_number(path: &Path, attribute: &str) -> Option<f64> {
+    read(path, attribute)?.parse().ok()
+}
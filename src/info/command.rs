@@ -0,0 +1,68 @@
+use std::io::Read;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Run `program` and wait at most `timeout` for it, `None` unless it exits successfully
+//
+// The output is read on another thread so the wait can give up. On Unix the
+// command gets its own process group, so giving up also stops whatever it started.
+pub fn run(program: &str, args: &[&str], timeout: Duration) -> Option<String> {
+    let mut command = Command::new(program);
+    command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+    #[cfg(unix)]
+    command.process_group(0);
+    let mut child = command.spawn().ok()?;
+
+    let mut stdout = child.stdout.take()?;
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        let _ = stdout.read_to_end(&mut output);
+        let _ = sender.send(output);
+    });
+
+    let started = Instant::now();
+    let output = receiver.recv_timeout(timeout).ok();
+    // Without output by the deadline something still holds stdout open, the
+    // command or whatever it left running in the background, so stop them all
+    if output.is_none() {
+        kill(&mut child);
+    }
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if output.is_some() && started.elapsed() < timeout => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            _ => {
+                kill(&mut child);
+                let _ = child.wait();
+                break None;
+            }
+        }
+    };
+
+    if !status?.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output?).into_owned())
+}
+
+// The whole group, a shell alone would leave its children running
+#[cfg(unix)]
+fn kill(child: &mut Child) {
+    unsafe { libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL) };
+}
+
+#[cfg(not(unix))]
+fn kill(child: &mut Child) {
+    let _ = child.kill();
+}
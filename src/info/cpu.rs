@@ -0,0 +1,66 @@
+use serde::Serialize;
+use std::fs;
+use sysinfo::System;
+
+#[derive(Debug, Serialize)]
+pub struct CpuInfo {
+    pub brand: String,
+    pub physical_cores: Option<usize>,
+    pub logical_cores: usize,
+    /// Average over all cores, in MHz
+    pub frequency_mhz: u64,
+    pub max_frequency_mhz: Option<u64>,
+    /// Usage over all cores since the previous refresh
+    pub usage_percentage: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoadInfo {
+    pub one: f64,
+    pub five: f64,
+    pub fifteen: f64,
+}
+
+const MAX_FREQUENCY: &str = "/sys/devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq";
+
+/// Usage is only meaningful once the CPUs were refreshed twice,
+/// `MINIMUM_CPU_UPDATE_INTERVAL` apart (see `Sources::new`)
+pub fn cpu(sys: &System) -> CpuInfo {
+    let cpus = sys.cpus();
+    let brand = match cpus.first() {
+        Some(cpu) => cpu.brand().trim().to_string(),
+        None => "Unknown CPU".to_string(),
+    };
+    let frequency_mhz = match cpus.len() {
+        0 => 0,
+        count => cpus.iter().map(|cpu| cpu.frequency()).sum::<u64>() / count as u64,
+    };
+
+    CpuInfo {
+        brand,
+        physical_cores: sys.physical_core_count(),
+        logical_cores: cpus.len(),
+        frequency_mhz,
+        max_frequency_mhz: max_frequency(),
+        usage_percentage: sys.global_cpu_usage() as f64,
+    }
+}
+
+// cpufreq reports kHz, missing in most VMs
+fn max_frequency() -> Option<u64> {
+    let khz: u64 = fs::read_to_string(MAX_FREQUENCY)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(khz / 1000)
+}
+
+pub fn load() -> LoadInfo {
+    let load = System::load_average();
+    LoadInfo {
+        one: load.one,
+        five: load.five,
+        fifteen: load.fifteen,
+    }
+}
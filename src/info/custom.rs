@@ -0,0 +1,97 @@
+use super::command;
+use crate::config::CustomConfig;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Serialize)]
+pub struct CustomInfo {
+    pub name: String,
+    /// `None` when the command failed, timed out or printed nothing
+    pub output: Option<String>,
+}
+
+/// Output of the `[[custom]]` commands named in `order`, in config order
+///
+/// Commands that are not shown are not run either.
+pub fn custom(configs: &[CustomConfig], order: &[String]) -> Vec<CustomInfo> {
+    configs
+        .iter()
+        .filter(|config| order.contains(&config.name))
+        .map(|config| CustomInfo {
+            name: config.name.clone(),
+            output: custom_output(config),
+        })
+        .collect()
+}
+
+/// First line a custom command prints, from the cache while it is younger than `ttl`
+pub fn custom_output(config: &CustomConfig) -> Option<String> {
+    if config.command.trim().is_empty() {
+        return None;
+    }
+
+    let cache = cache_path(&config.name).filter(|_| config.ttl > 0);
+    if let Some(output) = cache.as_ref().and_then(|path| cached(path, config)) {
+        return Some(output);
+    }
+
+    let output = command::run("sh", &["-c", &config.command], timeout(config))?;
+    let output = first_line(&output)?;
+    if let Some(path) = &cache {
+        // A cache that cannot be written only costs another run
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(path, format!("{}\n{}", config.command, output));
+    }
+    Some(output)
+}
+
+// Garbage like a negative or NaN timeout means no time at all
+fn timeout(config: &CustomConfig) -> Duration {
+    Duration::try_from_secs_f64(config.timeout).unwrap_or(Duration::ZERO)
+}
+
+fn first_line(output: &str) -> Option<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
+// `~/.cache/meowfetch/custom/<name>`
+fn cache_path(name: &str) -> Option<PathBuf> {
+    let file: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    Some(
+        dirs::cache_dir()?
+            .join("meowfetch")
+            .join("custom")
+            .join(file),
+    )
+}
+
+// The cache starts with the command, so editing the command
+// in the config does not show the output of the old one
+fn cached(path: &Path, config: &CustomConfig) -> Option<String> {
+    let age = SystemTime::now()
+        .duration_since(fs::metadata(path).ok()?.modified().ok()?)
+        .ok()?;
+    if age.as_secs() >= config.ttl {
+        return None;
+    }
+    let content = fs::read_to_string(path).ok()?;
+    let output = content.strip_prefix(&config.command)?.strip_prefix('\n')?;
+    Some(output.to_string())
+}
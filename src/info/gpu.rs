@@ -0,0 +1,115 @@
+use serde::Serialize;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+#[derive(Debug, Serialize)]
+pub struct GpuInfo {
+    pub pci_address: String,
+    pub vendor_id: String,
+    pub device_id: String,
+    pub vendor: Option<String>,
+    pub model: Option<String>,
+    pub driver: Option<String>,
+}
+
+const PCI_DEVICES: &str = "/sys/bus/pci/devices";
+
+// Where distributions install the PCI ID database
+const PCI_IDS: &[&str] = &[
+    "/usr/share/hwdata/pci.ids",
+    "/usr/share/misc/pci.ids",
+    "/usr/share/pci.ids",
+    "/var/lib/pciutils/pci.ids",
+];
+
+/// Every display controller (PCI class 0x03xxxx) on the system
+pub fn gpus() -> Vec<GpuInfo> {
+    let entries = match fs::read_dir(PCI_DEVICES) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut gpus: Vec<GpuInfo> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let class = read_id(&path.join("class"))?;
+            if !class.starts_with("03") {
+                return None;
+            }
+            let driver = fs::read_link(path.join("driver"))
+                .ok()
+                .and_then(|driver| Some(driver.file_name()?.to_string_lossy().to_string()));
+
+            Some(GpuInfo {
+                pci_address: entry.file_name().to_string_lossy().to_string(),
+                vendor_id: read_id(&path.join("vendor"))?,
+                device_id: read_id(&path.join("device"))?,
+                vendor: None,
+                model: None,
+                driver,
+            })
+        })
+        .collect();
+
+    gpus.sort_by(|a, b| a.pci_address.cmp(&b.pci_address));
+    if !gpus.is_empty() {
+        resolve_names(&mut gpus);
+    }
+    gpus
+}
+
+// sysfs ids look like `0x10de`, pci.ids uses lowercase hex without the prefix
+fn read_id(path: &Path) -> Option<String> {
+    let id = fs::read_to_string(path).ok()?;
+    Some(id.trim().trim_start_matches("0x").to_lowercase())
+}
+
+// Fill in vendor and device names from the first pci.ids file found
+//
+// The file lists every vendor at the start of a line, followed by its
+// devices indented with one tab and subsystems indented with two.
+fn resolve_names(gpus: &mut [GpuInfo]) {
+    let file = match PCI_IDS.iter().find_map(|path| fs::File::open(path).ok()) {
+        Some(file) => file,
+        None => return,
+    };
+
+    let mut vendor_id = String::new();
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        // Device classes follow the vendor list, nothing more to find
+        if line.starts_with("C ") {
+            break;
+        }
+
+        if let Some(device) = line.strip_prefix('\t') {
+            if device.starts_with('\t') {
+                continue;
+            }
+            if let Some((id, name)) = split_entry(device) {
+                for gpu in gpus.iter_mut() {
+                    if gpu.vendor_id == vendor_id && gpu.device_id == id {
+                        gpu.model = Some(name.to_string());
+                    }
+                }
+            }
+        } else if let Some((id, name)) = split_entry(&line) {
+            vendor_id = id.to_string();
+            for gpu in gpus.iter_mut() {
+                if gpu.vendor_id == id {
+                    gpu.vendor = Some(name.to_string());
+                }
+            }
+        }
+    }
+}
+
+// `10de  NVIDIA Corporation` -> ("10de", "NVIDIA Corporation")
+fn split_entry(line: &str) -> Option<(&str, &str)> {
+    let (id, name) = line.split_once("  ")?;
+    Some((id.trim(), name.trim()))
+}
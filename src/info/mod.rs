@@ -0,0 +1,262 @@
+mod battery;
+mod command;
+mod cpu;
+mod custom;
+mod gpu;
+mod network;
+mod packages;
+mod shell;
+mod temp;
+mod theme;
+
+pub use battery::{power, BatteryInfo, PowerInfo};
+pub use cpu::{cpu, load, CpuInfo, LoadInfo};
+pub use custom::{custom, custom_output, CustomInfo};
+pub use gpu::{gpus, GpuInfo};
+pub use network::{networks, NetInfo, Scope};
+pub use packages::{packages, PackageCount};
+pub use shell::{shell, terminal, ShellInfo};
+pub use temp::{temperatures, TempInfo};
+pub use theme::theme_colors;
+
+use crate::config::{Config, DiskConfig, DiskSort};
+use crate::pattern;
+use serde::Serialize;
+use std::fs;
+use std::thread;
+use sysinfo::{Components, Disks, Networks, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+// Plain data collected from the system, shared by the text and JSON output.
+// Sizes are always in bytes, formatting is left to the renderer.
+
+#[derive(Debug, Serialize)]
+pub struct OsInfo {
+    pub name: String,
+    pub version: String,
+    /// `ID` and `ID_LIKE` from os-release, e.g. `ubuntu` like `debian`
+    pub id: Option<String>,
+    pub id_like: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemoryInfo {
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiskInfo {
+    pub device: String,
+    pub mount_point: String,
+    pub file_system: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+}
+
+/// Everything meowfetch knows about the machine, used by `--json`
+#[derive(Debug, Serialize)]
+pub struct FetchInfo {
+    pub user: String,
+    pub host: Option<String>,
+    pub os: OsInfo,
+    pub kernel: Option<String>,
+    pub uptime_seconds: u64,
+    pub packages: Vec<PackageCount>,
+    pub shell: Option<ShellInfo>,
+    pub terminal: Option<String>,
+    pub cpu: CpuInfo,
+    pub load: LoadInfo,
+    pub gpus: Vec<GpuInfo>,
+    pub memory: MemoryInfo,
+    pub swap: MemoryInfo,
+    pub networks: Vec<NetInfo>,
+    pub disks: Vec<DiskInfo>,
+    pub power: PowerInfo,
+    pub temperatures: Vec<TempInfo>,
+    pub custom: Vec<CustomInfo>,
+}
+
+impl MemoryInfo {
+    /// `None` without any memory, e.g. swap on a machine that has none
+    pub fn used_percentage(&self) -> Option<f64> {
+        percentage(self.used_bytes, self.total_bytes)
+    }
+}
+
+impl DiskInfo {
+    pub fn used_bytes(&self) -> u64 {
+        self.total_bytes.saturating_sub(self.available_bytes)
+    }
+
+    pub fn used_percentage(&self) -> Option<f64> {
+        percentage(self.used_bytes(), self.total_bytes)
+    }
+}
+
+fn percentage(used: u64, total: u64) -> Option<f64> {
+    (total > 0).then(|| used as f64 / total as f64 * 100.0)
+}
+
+pub fn host_name() -> Option<String> {
+    System::host_name().filter(|host| !host.is_empty())
+}
+
+pub fn os() -> OsInfo {
+    let release = ["/etc/os-release", "/usr/lib/os-release"]
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .unwrap_or_default();
+    let field = |key: &str| {
+        release.lines().find_map(|line| {
+            let value = line.strip_prefix(key)?.strip_prefix('=')?;
+            Some(value.trim().trim_matches('"').to_string())
+        })
+    };
+
+    // Get system name and OS version, or use "Unknown" if unavailable
+    OsInfo {
+        name: System::name().unwrap_or("unknown".to_string()),
+        version: System::os_version().unwrap_or("unknown".to_string()),
+        id: field("ID"),
+        id_like: field("ID_LIKE")
+            .map(|like| like.split_whitespace().map(str::to_string).collect())
+            .unwrap_or_default(),
+    }
+}
+
+pub fn kernel() -> Option<String> {
+    System::kernel_version()
+}
+
+pub fn uptime() -> u64 {
+    System::uptime()
+}
+
+pub fn memory(sys: &System) -> MemoryInfo {
+    MemoryInfo {
+        total_bytes: sys.total_memory(),
+        used_bytes: sys.used_memory(),
+    }
+}
+
+pub fn swap(sys: &System) -> MemoryInfo {
+    MemoryInfo {
+        total_bytes: sys.total_swap(),
+        used_bytes: sys.used_swap(),
+    }
+}
+
+/// Mounted disks, filtered, deduplicated, sorted and limited as configured
+pub fn disks(disks: &Disks, config: &DiskConfig) -> Vec<DiskInfo> {
+    let mut result: Vec<DiskInfo> = Vec::new();
+
+    for disk in disks.list() {
+        let info = DiskInfo {
+            device: disk.name().to_string_lossy().to_string(),
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            file_system: disk.file_system().to_string_lossy().to_string(),
+            total_bytes: disk.total_space(),
+            available_bytes: disk.available_space(),
+        };
+
+        // Nothing to measure on empty drives and autofs placeholders
+        if info.total_bytes == 0
+            || (!config.include_fs.is_empty() && !config.include_fs.contains(&info.file_system))
+            || config.exclude_fs.contains(&info.file_system)
+            || (!config.include_mounts.is_empty()
+                && !pattern::any_match(&config.include_mounts, &info.mount_point))
+            || pattern::any_match(&config.exclude_mounts, &info.mount_point)
+        {
+            continue;
+        }
+
+        // Bind mounts share the device, keep the shortest mount point of each.
+        // Pseudo file systems have names like `tmpfs` instead of a device path.
+        if config.dedupe && info.device.starts_with('/') {
+            if let Some(seen) = result.iter_mut().find(|seen| seen.device == info.device) {
+                if info.mount_point.len() < seen.mount_point.len() {
+                    *seen = info;
+                }
+                continue;
+            }
+        }
+        result.push(info);
+    }
+
+    match config.sort {
+        DiskSort::None => {}
+        DiskSort::Usage => {
+            let usage = |disk: &DiskInfo| disk.used_percentage().unwrap_or(0.0);
+            result.sort_by(|a, b| usage(b).total_cmp(&usage(a)))
+        }
+        DiskSort::Size => result.sort_by_key(|disk| std::cmp::Reverse(disk.total_bytes)),
+        DiskSort::Mount => result.sort_by(|a, b| a.mount_point.cmp(&b.mount_point)),
+    }
+    if config.limit > 0 {
+        result.truncate(config.limit);
+    }
+    result
+}
+
+/// The sysinfo handles everything is read from, kept alive so `--watch` can refresh them
+pub struct Sources {
+    pub sys: System,
+    pub disks: Disks,
+    pub networks: Networks,
+    pub components: Components,
+}
+
+impl Default for Sources {
+    fn default() -> Self {
+        Sources::new()
+    }
+}
+
+impl Sources {
+    pub fn new() -> Sources {
+        let mut sys = System::new_all();
+        sys.refresh_all();
+        // CPU usage is the difference between two samples
+        thread::sleep(MINIMUM_CPU_UPDATE_INTERVAL);
+        sys.refresh_cpu_all();
+        Sources {
+            sys,
+            disks: Disks::new_with_refreshed_list(),
+            networks: Networks::new_with_refreshed_list(),
+            components: Components::new_with_refreshed_list(),
+        }
+    }
+
+    /// Re-read what changes while meowfetch runs: CPU, memory, swap, disk usage, addresses and sensors
+    pub fn refresh(&mut self) {
+        self.sys.refresh_cpu_all();
+        self.sys.refresh_memory();
+        self.disks.refresh(true);
+        self.networks.refresh(true);
+        self.components.refresh(true);
+    }
+}
+
+pub fn collect(config: &Config, sources: &Sources) -> FetchInfo {
+    let sys = &sources.sys;
+    FetchInfo {
+        user: whoami::username(),
+        host: host_name(),
+        os: os(),
+        kernel: kernel(),
+        uptime_seconds: uptime(),
+        packages: packages(),
+        shell: shell(),
+        terminal: terminal(sys),
+        cpu: cpu(sys),
+        load: load(),
+        gpus: gpus(),
+        memory: memory(sys),
+        swap: swap(sys),
+        networks: networks(&sources.networks, &config.network),
+        disks: disks(&sources.disks, &config.disk),
+        power: power(),
+        temperatures: temperatures(&sources.components, &config.temp),
+        custom: custom(&config.custom, &config.modules.order),
+    }
+}
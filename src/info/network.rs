@@ -0,0 +1,140 @@
+use crate::config::NetworkConfig;
+use crate::pattern;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs;
+use std::net::IpAddr;
+use sysinfo::Networks;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Scope {
+    Loopback,
+    LinkLocal,
+    /// IPv6 unique local addresses (fc00::/7), routed inside a site only
+    UniqueLocal,
+    Global,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NetInfo {
+    pub interface: String,
+    pub address: String,
+    pub prefix: u8,
+    pub ipv6: bool,
+    pub scope: Scope,
+}
+
+/// Addresses of all interfaces that pass the configured filters
+pub fn networks(networks: &Networks, config: &NetworkConfig) -> Vec<NetInfo> {
+    let default_routes = if config.default_route_only {
+        Some(default_route_interfaces())
+    } else {
+        None
+    };
+    let mut result = Vec::new();
+
+    for (interface_name, network) in networks {
+        if (!config.include.is_empty() && !pattern::any_match(&config.include, interface_name))
+            || pattern::any_match(&config.exclude, interface_name)
+        {
+            continue;
+        }
+        if let Some(default_routes) = &default_routes {
+            if !default_routes.contains(interface_name) {
+                continue;
+            }
+        }
+
+        for ip_network in network.ip_networks() {
+            result.push(NetInfo {
+                interface: interface_name.clone(),
+                address: ip_network.addr.to_string(),
+                prefix: ip_network.prefix,
+                ipv6: ip_network.addr.is_ipv6(),
+                scope: scope(&ip_network.addr),
+            });
+        }
+    }
+
+    // Interfaces and their addresses come out of hash maps, sort them so the
+    // output (and the `--redact` numbering) is the same on every run
+    result.sort_by_cached_key(|net| {
+        let address = net.address.parse::<IpAddr>().ok();
+        (net.interface.clone(), net.ipv6, address)
+    });
+    result
+}
+
+fn scope(addr: &IpAddr) -> Scope {
+    match addr {
+        IpAddr::V4(v4) if v4.is_loopback() => Scope::Loopback,
+        IpAddr::V4(v4) if v4.is_link_local() => Scope::LinkLocal,
+        IpAddr::V4(_) => Scope::Global,
+        IpAddr::V6(v6) if v6.is_loopback() => Scope::Loopback,
+        IpAddr::V6(v6) if v6.segments()[0] & 0xffc0 == 0xfe80 => Scope::LinkLocal,
+        IpAddr::V6(v6) if v6.segments()[0] & 0xfe00 == 0xfc00 => Scope::UniqueLocal,
+        IpAddr::V6(_) => Scope::Global,
+    }
+}
+
+// Interfaces carrying an IPv4 or IPv6 default route, read from the kernel routing tables
+fn default_route_interfaces() -> HashSet<String> {
+    let mut interfaces = HashSet::new();
+
+    // Iface Destination Gateway Flags RefCnt Use Metric Mask ...
+    if let Ok(routes) = fs::read_to_string("/proc/net/route") {
+        for line in routes.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() > 7 && fields[1] == "00000000" && fields[7] == "00000000" {
+                interfaces.insert(fields[0].to_string());
+            }
+        }
+    }
+
+    // Destination DestPrefix Source SourcePrefix NextHop Metric RefCnt Use Flags Iface
+    if let Ok(routes) = fs::read_to_string("/proc/net/ipv6_route") {
+        for line in routes.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() > 9
+                && fields[1] == "00"
+                && fields[0].chars().all(|c| c == '0')
+                && fields[9] != "lo"
+            {
+                interfaces.insert(fields[9].to_string());
+            }
+        }
+    }
+
+    interfaces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scope_of(addr: &str) -> Scope {
+        scope(&addr.parse().unwrap())
+    }
+
+    #[test]
+    fn classifies_ipv4_scopes() {
+        assert_eq!(scope_of("127.0.0.1"), Scope::Loopback);
+        assert_eq!(scope_of("169.254.10.20"), Scope::LinkLocal);
+        assert_eq!(scope_of("192.168.1.5"), Scope::Global);
+        assert_eq!(scope_of("8.8.8.8"), Scope::Global);
+    }
+
+    #[test]
+    fn classifies_ipv6_scopes() {
+        assert_eq!(scope_of("::1"), Scope::Loopback);
+        assert_eq!(scope_of("fe80::1"), Scope::LinkLocal);
+        assert_eq!(scope_of("febf::1"), Scope::LinkLocal);
+        assert_eq!(scope_of("fd12:3456::1"), Scope::UniqueLocal);
+        assert_eq!(scope_of("fc00::1"), Scope::UniqueLocal);
+        assert_eq!(scope_of("2001:db8::1"), Scope::Global);
+        // Just outside the link-local and unique-local prefixes
+        assert_eq!(scope_of("fec0::1"), Scope::Global);
+        assert_eq!(scope_of("fe00::1"), Scope::Global);
+    }
+}
@@ -0,0 +1,190 @@
+use super::command;
+use serde::Serialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Serialize)]
+pub struct PackageCount {
+    pub manager: String,
+    pub count: usize,
+}
+
+// Binaries rustup installs into ~/.cargo/bin, they are not installed crates
+const RUSTUP_PROXIES: &[&str] = &[
+    "cargo",
+    "cargo-clippy",
+    "cargo-fmt",
+    "cargo-miri",
+    "clippy-driver",
+    "rls",
+    "rust-analyzer",
+    "rust-gdb",
+    "rust-gdbgui",
+    "rust-lldb",
+    "rustc",
+    "rustdoc",
+    "rustfmt",
+    "rustup",
+];
+
+// A locked rpm database must not hold up the whole fetch, same limit as the shell version
+const RPM_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Installed packages per package manager, managers with nothing installed are left out
+pub fn packages() -> Vec<PackageCount> {
+    let home = dirs::home_dir();
+    let counters: Vec<(&str, Option<usize>)> = vec![
+        ("dpkg", dpkg()),
+        ("rpm", rpm()),
+        ("pacman", count_dirs(Path::new("/var/lib/pacman/local"))),
+        ("apk", apk()),
+        ("xbps", xbps()),
+        ("flatpak", flatpak(home.as_deref())),
+        ("snap", snap()),
+        ("nix", nix(home.as_deref())),
+        ("cargo", cargo(home.as_deref())),
+    ];
+
+    counters
+        .into_iter()
+        .filter_map(|(manager, count)| match count {
+            Some(count) if count > 0 => Some(PackageCount {
+                manager: manager.to_string(),
+                count,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+// Every installed package has a paragraph with this exact status line
+fn dpkg() -> Option<usize> {
+    let status = fs::read_to_string("/var/lib/dpkg/status").ok()?;
+    Some(
+        status
+            .lines()
+            .filter(|line| *line == "Status: install ok installed")
+            .count(),
+    )
+}
+
+// The rpm database is SQLite or Berkeley DB, ask rpm itself but only when a database exists
+fn rpm() -> Option<usize> {
+    let databases = [
+        "/var/lib/rpm/rpmdb.sqlite",
+        "/var/lib/rpm/Packages",
+        "/usr/lib/sysimage/rpm/rpmdb.sqlite",
+        "/usr/lib/sysimage/rpm/Packages.db",
+    ];
+    if !databases.iter().any(|path| Path::new(path).exists()) {
+        return None;
+    }
+    let output = command::run("rpm", &["-qa", "--qf", ".\n"], RPM_TIMEOUT)?;
+    Some(output.lines().count())
+}
+
+// One `P:` (package name) line per installed package
+fn apk() -> Option<usize> {
+    let installed = fs::read_to_string("/lib/apk/db/installed").ok()?;
+    Some(
+        installed
+            .lines()
+            .filter(|line| line.starts_with("P:"))
+            .count(),
+    )
+}
+
+// Every package dictionary in the pkgdb has exactly one pkgver key
+fn xbps() -> Option<usize> {
+    let pkgdb = fs::read_dir("/var/db/xbps")
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name.starts_with("pkgdb-") && name.ends_with(".plist")
+        })?;
+    let content = fs::read_to_string(pkgdb).ok()?;
+    Some(content.matches("<key>pkgver</key>").count())
+}
+
+// System and user installations, apps and runtimes alike
+fn flatpak(home: Option<&Path>) -> Option<usize> {
+    let mut roots = vec![PathBuf::from("/var/lib/flatpak")];
+    if let Some(home) = home {
+        roots.push(home.join(".local/share/flatpak"));
+    }
+    sum(roots.iter().flat_map(|root| {
+        [
+            count_dirs(&root.join("app")),
+            count_dirs(&root.join("runtime")),
+        ]
+    }))
+}
+
+// Mounted snaps live in /snap/<name>, /snap/bin holds the wrappers
+fn snap() -> Option<usize> {
+    let entries = fs::read_dir("/snap").ok()?;
+    Some(
+        entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir() && entry.file_name() != "bin")
+            .count(),
+    )
+}
+
+// `elements` of a profile manifest, a list in version 2 and a map in version 3
+fn nix(home: Option<&Path>) -> Option<usize> {
+    let mut manifests = vec![PathBuf::from("/nix/var/nix/profiles/default/manifest.json")];
+    if let Some(home) = home {
+        manifests.push(home.join(".nix-profile/manifest.json"));
+        manifests.push(home.join(".local/state/nix/profile/manifest.json"));
+    }
+    manifests.dedup_by(|a, b| fs::canonicalize(a).ok() == fs::canonicalize(b).ok());
+
+    sum(manifests.iter().map(|manifest| {
+        let content = fs::read_to_string(manifest).ok()?;
+        let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+        match &json["elements"] {
+            serde_json::Value::Array(elements) => Some(elements.len()),
+            serde_json::Value::Object(elements) => Some(elements.len()),
+            _ => None,
+        }
+    }))
+}
+
+// Binaries installed with `cargo install`
+fn cargo(home: Option<&Path>) -> Option<usize> {
+    let cargo_home = match env::var_os("CARGO_HOME") {
+        Some(cargo_home) => PathBuf::from(cargo_home),
+        None => home?.join(".cargo"),
+    };
+    let entries = fs::read_dir(cargo_home.join("bin")).ok()?;
+    Some(
+        entries
+            .flatten()
+            .filter(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let name = name.trim_end_matches(".exe");
+                !RUSTUP_PROXIES.contains(&name)
+            })
+            .count(),
+    )
+}
+
+fn count_dirs(path: &Path) -> Option<usize> {
+    let entries = fs::read_dir(path).ok()?;
+    Some(
+        entries
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .count(),
+    )
+}
+
+// Add up the counts that exist, None if there was nothing to count at all
+fn sum(counts: impl Iterator<Item = Option<usize>>) -> Option<usize> {
+    counts.flatten().reduce(|total, count| total + count)
+}
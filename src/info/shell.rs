@@ -0,0 +1,163 @@
+use super::command;
+use serde::Serialize;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use sysinfo::{Pid, System};
+
+#[derive(Debug, Serialize)]
+pub struct ShellInfo {
+    pub name: String,
+    pub path: String,
+    pub version: Option<String>,
+}
+
+const SHELL_TIMEOUT: Duration = Duration::from_millis(500);
+
+// Processes that sit between the terminal and meowfetch without being the terminal
+const SHELLS: &[&str] = &[
+    "sh", "bash", "zsh", "fish", "dash", "ksh", "mksh", "tcsh", "csh", "nu", "elvish", "xonsh",
+    "ion", "oil", "osh", "pwsh",
+];
+const WRAPPERS: &[&str] = &[
+    "sudo",
+    "su",
+    "doas",
+    "run0",
+    "script",
+    "cargo",
+    "meowfetch",
+    "watch",
+    "env",
+    "time",
+    "timeout",
+    "nohup",
+    "nice",
+    "ionice",
+    "chrt",
+    "taskset",
+    "setsid",
+    "stdbuf",
+    "flock",
+    "perf",
+    "valgrind",
+    "strace",
+    "ltrace",
+    "gdb",
+];
+
+/// Login shell from `$SHELL`, falling back to the user's `/etc/passwd` entry
+pub fn shell() -> Option<ShellInfo> {
+    let path = shell_path()?;
+    let name = Path::new(&path).file_name()?.to_string_lossy().to_string();
+    let version = shell_version(&path);
+
+    Some(ShellInfo {
+        name,
+        path,
+        version,
+    })
+}
+
+fn shell_path() -> Option<String> {
+    env::var("SHELL")
+        .ok()
+        .filter(|path| !path.is_empty())
+        .or_else(passwd_shell)
+}
+
+fn passwd_shell() -> Option<String> {
+    let username = whoami::username();
+    let passwd = fs::read_to_string("/etc/passwd").ok()?;
+    passwd.lines().find_map(|line| {
+        let fields: Vec<&str> = line.split(':').collect();
+        if fields.len() == 7 && fields[0] == username {
+            Some(fields[6].to_string())
+        } else {
+            None
+        }
+    })
+}
+
+// Ask the shell itself, `bash --version` and friends print the version on the first line
+fn shell_version(path: &str) -> Option<String> {
+    // An odd `$SHELL` might not return from `--version`, or wait for input
+    let stdout = command::run(path, &["--version"], SHELL_TIMEOUT)?;
+    let first_line = stdout.lines().next()?;
+
+    // The first word starting with a digit, cut at the first character that is not part of the number
+    first_line.split_whitespace().find_map(|word| {
+        let version: String = word
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        if version.is_empty() || version.starts_with('.') {
+            None
+        } else {
+            Some(version.trim_end_matches('.').to_string())
+        }
+    })
+}
+
+/// Walk up the process tree until something that is neither a shell nor a wrapper shows up
+pub fn terminal(sys: &System) -> Option<String> {
+    let shell_name = shell_path().and_then(|path| {
+        let name = Path::new(&path).file_name()?;
+        Some(name.to_string_lossy().to_string())
+    });
+    let mut pid = sysinfo::get_current_pid().ok();
+
+    while let Some(current) = pid {
+        // A parent we cannot see (containers, hidepid) ends the walk
+        let (Some(process), Some(name)) = (sys.process(current), process_name(sys, current)) else {
+            break;
+        };
+        pid = process.parent();
+
+        if SHELLS.contains(&name.as_str())
+            || WRAPPERS.contains(&name.as_str())
+            || shell_name.as_deref() == Some(name.as_str())
+        {
+            continue;
+        }
+
+        return match name.as_str() {
+            // Reached the top without passing a terminal emulator
+            "login" | "init" | "systemd" | "agetty" => tty_name(),
+            "sshd" | "sshd-session" => Some("ssh".to_string()),
+            "gnome-terminal-server" | "gnome-terminal-" => Some("gnome-terminal".to_string()),
+            "wezterm-gui" => Some("wezterm".to_string()),
+            "tmux: server" | "tmux: client" => Some("tmux".to_string()),
+            _ => Some(name),
+        };
+    }
+
+    // Nothing useful in the process tree, trust the terminal to introduce itself
+    env::var("TERM_PROGRAM").ok().or_else(tty_name)
+}
+
+// Prefer the executable name, `name()` is cut to 15 characters on Linux
+fn process_name(sys: &System, pid: Pid) -> Option<String> {
+    let process = sys.process(pid)?;
+    let exe_name = process
+        .exe()
+        .and_then(|exe| exe.file_name())
+        .map(|name| name.to_string_lossy().to_string());
+    let name = process.name().to_string_lossy().to_string();
+
+    match exe_name {
+        Some(exe_name) if exe_name.starts_with(&name) => Some(exe_name),
+        _ => Some(name).filter(|name| !name.is_empty()),
+    }
+}
+
+fn tty_name() -> Option<String> {
+    let tty = fs::read_link("/proc/self/fd/0").ok()?;
+    let tty = tty.to_string_lossy();
+    if tty.starts_with("/dev/tty") {
+        Some(tty.trim_start_matches("/dev/").to_string())
+    } else {
+        None
+    }
+}
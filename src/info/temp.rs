@@ -0,0 +1,96 @@
+use crate::config::TempConfig;
+use crate::pattern;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use sysinfo::Components;
+
+#[derive(Debug, Serialize)]
+pub struct TempInfo {
+    /// `<chip> <sensor>`, e.g. `coretemp Package id 0` or `nvme Composite`
+    pub label: String,
+    pub celsius: f64,
+    pub critical_celsius: Option<f64>,
+}
+
+const HWMON: &str = "/sys/class/hwmon";
+
+/// Sensors whose label matches the configured globs, in label order
+pub fn temperatures(components: &Components, config: &TempConfig) -> Vec<TempInfo> {
+    let mut sensors: Vec<TempInfo> = components
+        .list()
+        .iter()
+        .filter_map(|component| {
+            Some(TempInfo {
+                label: component.label().to_string(),
+                celsius: component.temperature().filter(|t| t.is_finite())? as f64,
+                critical_celsius: component.critical().map(f64::from),
+            })
+        })
+        .collect();
+
+    // sysinfo skips chips it cannot label, read hwmon directly then
+    if sensors.is_empty() {
+        sensors = hwmon();
+    }
+
+    sensors.retain(|sensor| {
+        config.sensors.is_empty() || pattern::any_match(&config.sensors, &sensor.label)
+    });
+    sensors.sort_by(|a, b| a.label.cmp(&b.label));
+    sensors
+}
+
+// Every `temp*_input` under /sys/class/hwmon, labeled the same way sysinfo does
+fn hwmon() -> Vec<TempInfo> {
+    let mut sensors = Vec::new();
+    let entries = match fs::read_dir(HWMON) {
+        Ok(entries) => entries,
+        Err(_) => return sensors,
+    };
+
+    for chip in entries.flatten().map(|entry| entry.path()) {
+        let name = read(&chip, "name").unwrap_or_else(|| "hwmon".to_string());
+        let inputs = match fs::read_dir(&chip) {
+            Ok(inputs) => inputs,
+            Err(_) => continue,
+        };
+
+        for input in inputs.flatten() {
+            let file = input.file_name().to_string_lossy().to_string();
+            let sensor = match file
+                .strip_prefix("temp")
+                .and_then(|rest| rest.strip_suffix("_input"))
+            {
+                Some(sensor) => sensor.to_string(),
+                None => continue,
+            };
+
+            // Values are in millidegrees Celsius
+            let millidegrees = |suffix: &str| -> Option<f64> {
+                read(&chip, &format!("temp{}_{}", sensor, suffix))?
+                    .parse()
+                    .ok()
+            };
+            let celsius = match millidegrees("input") {
+                Some(value) => value / 1000.0,
+                None => continue,
+            };
+            let label = read(&chip, &format!("temp{}_label", sensor))
+                .unwrap_or_else(|| format!("temp{}", sensor));
+
+            sensors.push(TempInfo {
+                label: format!("{} {}", name, label),
+                celsius,
+                critical_celsius: millidegrees("crit").map(|value| value / 1000.0),
+            });
+        }
+    }
+
+    sensors
+}
+
+fn read(dir: &Path, file: &str) -> Option<String> {
+    let content = fs::read_to_string(dir.join(file)).ok()?;
+    Some(content.trim().to_string())
+}
@@ -0,0 +1,53 @@
+use crate::tty;
+
+/// The 16 ANSI colors of the current terminal theme, asked for with OSC 4
+///
+/// Entries the terminal did not report are `None`, and the whole result is
+/// `None` when there is no terminal or it answered nothing.
+pub fn theme_colors() -> Option<Vec<Option<(u8, u8, u8)>>> {
+    let mut request = String::new();
+    for index in 0..16 {
+        request.push_str(&format!("\x1b]4;{};?\x1b\\", index));
+    }
+
+    let colors = parse(&tty::query(&request)?);
+    colors.iter().any(Option::is_some).then_some(colors)
+}
+
+// Replies look like `ESC ]4;1;rgb:cccc/2424/1d1d ESC \` (or ending in BEL)
+fn parse(response: &str) -> Vec<Option<(u8, u8, u8)>> {
+    let mut colors = vec![None; 16];
+    for reply in response.split("\x1b]4;").skip(1) {
+        let reply = reply.split(['\x07', '\x1b']).next().unwrap_or("");
+        let (index, spec) = match reply.split_once(';') {
+            Some(parts) => parts,
+            None => continue,
+        };
+        let index: usize = match index.parse() {
+            Ok(index) if index < 16 => index,
+            _ => continue,
+        };
+        colors[index] = parse_rgb(spec);
+    }
+    colors
+}
+
+// X11 color spec, every channel has 1 to 4 hex digits
+fn parse_rgb(spec: &str) -> Option<(u8, u8, u8)> {
+    let channels: Vec<u8> = spec
+        .strip_prefix("rgb:")?
+        .split('/')
+        .map(|channel| {
+            if !(1..=4).contains(&channel.len()) {
+                return None;
+            }
+            let value = u32::from_str_radix(channel, 16).ok()?;
+            let max = (1u32 << (4 * channel.len())) - 1;
+            Some((value * 255 / max) as u8)
+        })
+        .collect::<Option<_>>()?;
+    match channels[..] {
+        [r, g, b] => Some((r, g, b)),
+        _ => None,
+    }
+}
@@ -0,0 +1,35 @@
+//! System information next to a cat, as printed by the `meowfetch` binary.
+//!
+//! The pieces can be used on their own: [`info`] collects typed data from the
+//! system, [`modules`] turns it into colored info lines and [`render`] lays
+//! those lines out next to a logo from [`logo`].
+//!
+//! ```no_run
+//! use meowfetch::config::Config;
+//! use meowfetch::info::Sources;
+//! use meowfetch::modules::{self, Context};
+//! use meowfetch::{logo, render};
+//!
+//! let config = Config::default();
+//! let sources = Sources::new();
+//! let ctx = Context::new(&config, &sources, false);
+//!
+//! let names = ["title", "cpu", "mem"].map(String::from);
+//! let (modules, _unknown) = modules::select(&names, &config);
+//! let lines = modules::lines(&modules, &ctx);
+//! let (logo, _errors) = logo::select(None, None, &config.logo);
+//! print!("{}", render::format_fetch(&logo, &lines, 1));
+//! ```
+
+pub mod color;
+pub mod config;
+pub mod info;
+pub mod logo;
+pub mod modules;
+pub mod redact;
+pub mod render;
+pub mod units;
+pub mod width;
+
+mod pattern;
+mod tty;
@@ -0,0 +1,281 @@
+use std::fs;
+use std::path::Path;
+
+// Pixels more transparent than this become plain spaces
+pub(super) const ALPHA_THRESHOLD: u32 = 128;
+
+// An RGBA image, row by row
+pub(super) struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<[u8; 4]>,
+}
+
+impl Image {
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 4] {
+        self.pixels[y * self.width + x]
+    }
+}
+
+/// Turn a PNG or PPM image into a half-block logo `width` cells wide
+///
+/// The result uses the same format as the built-in cats and `.meowrc`: every
+/// cell is a `▄` with the top pixel as background and the bottom pixel as
+/// foreground, `▀`/`▄` alone when only one of them is opaque, and a space when
+/// neither is. Escapes are written out as `\x1b` so it can be saved as is.
+pub fn convert(path: &Path, width: usize) -> Result<String, String> {
+    let image = load(path)?;
+    if image.width == 0 || image.height == 0 {
+        return Err("the image is empty".to_string());
+    }
+
+    // Two pixel rows per line of text, keep the aspect ratio
+    let width = width.clamp(1, image.width);
+    let mut height = (image.height * width + image.width / 2) / image.width;
+    height = height.max(1).next_multiple_of(2);
+    let scaled = resize(&image, width, height);
+
+    let mut lines = Vec::new();
+    for y in (0..height).step_by(2) {
+        let mut line = String::new();
+        for x in 0..width {
+            line.push_str(&cell(scaled.pixel(x, y), scaled.pixel(x, y + 1)));
+        }
+        lines.push(line);
+    }
+    Ok(lines.join("\n"))
+}
+
+fn cell(top: [u8; 4], bottom: [u8; 4]) -> String {
+    let opaque = |pixel: [u8; 4]| pixel[3] as u32 >= ALPHA_THRESHOLD;
+    match (opaque(top), opaque(bottom)) {
+        (true, true) => format!(
+            "\\x1b[48;2;{};{};{}m\\x1b[38;2;{};{};{}m▄\\x1b[0m",
+            top[0], top[1], top[2], bottom[0], bottom[1], bottom[2]
+        ),
+        (true, false) => format!("\\x1b[38;2;{};{};{}m▀\\x1b[0m", top[0], top[1], top[2]),
+        (false, true) => format!(
+            "\\x1b[38;2;{};{};{}m▄\\x1b[0m",
+            bottom[0], bottom[1], bottom[2]
+        ),
+        (false, false) => " ".to_string(),
+    }
+}
+
+// Box filter, every target pixel averages the source pixels it covers.
+// Colors are weighted by alpha so transparent pixels do not darken the edges.
+pub(super) fn resize(image: &Image, width: usize, height: usize) -> Image {
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let (top, bottom) = span(y, height, image.height);
+        for x in 0..width {
+            let (left, right) = span(x, width, image.width);
+
+            let mut sum = [0u64; 4];
+            for source_y in top..bottom {
+                for source_x in left..right {
+                    let pixel = image.pixel(source_x, source_y);
+                    let alpha = pixel[3] as u64;
+                    for channel in 0..3 {
+                        sum[channel] += pixel[channel] as u64 * alpha;
+                    }
+                    sum[3] += alpha;
+                }
+            }
+
+            let count = ((bottom - top) * (right - left)) as u64;
+            let pixel = match sum[3] {
+                0 => [0, 0, 0, 0],
+                alpha => [
+                    (sum[0] / alpha) as u8,
+                    (sum[1] / alpha) as u8,
+                    (sum[2] / alpha) as u8,
+                    (alpha / count) as u8,
+                ],
+            };
+            pixels.push(pixel);
+        }
+    }
+    Image {
+        width,
+        height,
+        pixels,
+    }
+}
+
+// Source range covered by target index `i`, at least one pixel wide
+fn span(i: usize, target: usize, source: usize) -> (usize, usize) {
+    let start = i * source / target;
+    let end = ((i + 1) * source / target).max(start + 1).min(source);
+    (start.min(source - 1), end)
+}
+
+pub(super) fn load(path: &Path) -> Result<Image, String> {
+    let data = fs::read(path).map_err(|err| err.to_string())?;
+    if data.starts_with(b"\x89PNG") {
+        load_png(&data)
+    } else {
+        load_ppm(&data)
+    }
+}
+
+fn load_png(data: &[u8]) -> Result<Image, String> {
+    let mut decoder = png::Decoder::new(data);
+    // Palettes and low bit depths become plain 8 bit channels
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(|err| err.to_string())?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let frame = reader
+        .next_frame(&mut buffer)
+        .map_err(|err| err.to_string())?;
+    let data = &buffer[..frame.buffer_size()];
+
+    let pixels = match frame.color_type {
+        png::ColorType::Rgba => data
+            .chunks_exact(4)
+            .map(|p| [p[0], p[1], p[2], p[3]])
+            .collect(),
+        png::ColorType::Rgb => data
+            .chunks_exact(3)
+            .map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => data
+            .chunks_exact(2)
+            .map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => data.iter().map(|&p| [p, p, p, 255]).collect(),
+        png::ColorType::Indexed => return Err("unsupported PNG color type".to_string()),
+    };
+    Ok(Image {
+        width: frame.width as usize,
+        height: frame.height as usize,
+        pixels,
+    })
+}
+
+// Binary (P6) and plain (P3) PPM, which have no transparency
+fn load_ppm(data: &[u8]) -> Result<Image, String> {
+    let error = || "not a PNG or PPM image".to_string();
+    let binary = match data.get(..2) {
+        Some(b"P6") => true,
+        Some(b"P3") => false,
+        _ => return Err(error()),
+    };
+
+    // Header fields are separated by whitespace, `#` starts a comment
+    let mut position = 2;
+    let mut fields = Vec::new();
+    while fields.len() < 3 {
+        while position < data.len() && data[position].is_ascii_whitespace() {
+            position += 1;
+        }
+        if data.get(position) == Some(&b'#') {
+            while position < data.len() && data[position] != b'\n' {
+                position += 1;
+            }
+            continue;
+        }
+        let start = position;
+        while position < data.len() && data[position].is_ascii_digit() {
+            position += 1;
+        }
+        let field: usize = std::str::from_utf8(&data[start..position])
+            .ok()
+            .and_then(|field| field.parse().ok())
+            .ok_or_else(error)?;
+        fields.push(field);
+    }
+    let (width, height, max) = (fields[0], fields[1], fields[2].max(1));
+    // The format allows at most 16 bits per sample
+    if max > 65535 {
+        return Err(error());
+    }
+    let pixel_count = width
+        .checked_mul(height)
+        .filter(|count| count.checked_mul(3).is_some())
+        .ok_or_else(|| "the PPM image is too large".to_string())?;
+
+    let samples: Vec<usize> = if binary {
+        // A single whitespace byte separates the header from the samples
+        let body = data.get(position + 1..).ok_or_else(error)?;
+        if max < 256 {
+            body.iter().map(|&sample| sample as usize).collect()
+        } else {
+            body.chunks_exact(2)
+                .map(|sample| (sample[0] as usize) << 8 | sample[1] as usize)
+                .collect()
+        }
+    } else {
+        std::str::from_utf8(&data[position..])
+            .map_err(|_| error())?
+            .split_ascii_whitespace()
+            .map(|sample| sample.parse().map_err(|_| error()))
+            .collect::<Result<_, _>>()?
+    };
+    if samples.len() < pixel_count * 3 {
+        return Err("the PPM image is truncated".to_string());
+    }
+
+    let scale = |sample: usize| (sample.min(max) * 255 / max) as u8;
+    let pixels = samples
+        .chunks_exact(3)
+        .take(pixel_count)
+        .map(|p| [scale(p[0]), scale(p[1]), scale(p[2]), 255])
+        .collect();
+    Ok(Image {
+        width,
+        height,
+        pixels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_binary_ppm() {
+        let mut data = b"P6\n# two pixels\n2 1\n255\n".to_vec();
+        data.extend_from_slice(&[255, 0, 0, 0, 128, 255]);
+        let image = load_ppm(&data).unwrap();
+        assert_eq!((image.width, image.height), (2, 1));
+        assert_eq!(image.pixels, vec![[255, 0, 0, 255], [0, 128, 255, 255]]);
+    }
+
+    #[test]
+    fn loads_16_bit_and_plain_ppm() {
+        let mut data = b"P6 1 1 65535 ".to_vec();
+        data.extend_from_slice(&[0xff, 0xff, 0x80, 0x00, 0x00, 0x00]);
+        assert_eq!(load_ppm(&data).unwrap().pixels, vec![[255, 127, 0, 255]]);
+
+        let image = load_ppm(b"P3\n1 2\n15\n15 0 0\n0 15 0\n").unwrap();
+        assert_eq!(image.pixels, vec![[255, 0, 0, 255], [0, 255, 0, 255]]);
+    }
+
+    #[test]
+    fn rejects_truncated_ppm() {
+        let mut data = b"P6\n2 2\n255\n".to_vec();
+        data.extend_from_slice(&[0; 9]);
+        assert_eq!(
+            load_ppm(&data).err().as_deref(),
+            Some("the PPM image is truncated")
+        );
+        assert!(load_ppm(b"P6\n2 2").is_err());
+        assert!(load_ppm(b"P5\n1 1\n255\n\0").is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_ppm() {
+        let data = format!("P6\n{} 2\n255\n", usize::MAX / 2);
+        assert_eq!(
+            load_ppm(data.as_bytes()).err().as_deref(),
+            Some("the PPM image is too large")
+        );
+        let data = format!("P6\n{} 1\n255\n", usize::MAX / 2);
+        assert_eq!(
+            load_ppm(data.as_bytes()).err().as_deref(),
+            Some("the PPM image is too large")
+        );
+        assert!(load_ppm(b"P6\n1 1\n70000\n\0\0\0").is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::color;
+
+// Accent color of every distribution with a tinted cat, by os-release `ID`
+const DISTROS: &[(&str, (u8, u8, u8))] = &[
+    ("almalinux", (255, 76, 84)),
+    ("alpine", (13, 89, 127)),
+    ("arch", (23, 147, 209)),
+    ("artix", (16, 164, 206)),
+    ("centos", (147, 34, 129)),
+    ("debian", (215, 10, 83)),
+    ("elementary", (100, 186, 255)),
+    ("endeavouros", (127, 63, 191)),
+    ("fedora", (81, 162, 218)),
+    ("gentoo", (84, 72, 122)),
+    ("kali", (38, 127, 210)),
+    ("linuxmint", (134, 190, 67)),
+    ("manjaro", (53, 191, 92)),
+    ("nixos", (126, 186, 228)),
+    ("opensuse", (115, 186, 37)),
+    ("pop", (72, 185, 199)),
+    ("rhel", (238, 0, 0)),
+    ("rocky", (16, 185, 129)),
+    ("slackware", (71, 89, 148)),
+    ("ubuntu", (233, 84, 32)),
+    ("void", (71, 128, 97)),
+];
+
+// Lightness of the fur the default cat is drawn with, tinted fur keeps the shading around it
+const FUR_LIGHTNESS: f64 = 153.0;
+
+/// Accent for a distribution, looked up by `ID` first and `ID_LIKE` after that.
+/// openSUSE ships several IDs (`opensuse-tumbleweed`, `opensuse-leap`, ...).
+pub fn accent<'a>(ids: impl IntoIterator<Item = &'a str>) -> Option<(u8, u8, u8)> {
+    ids.into_iter().find_map(|id| {
+        let id = id.strip_prefix("opensuse-").map_or(id, |_| "opensuse");
+        DISTROS
+            .iter()
+            .find(|(name, _)| *name == id)
+            .map(|(_, accent)| *accent)
+    })
+}
+
+/// Paint the gray fur of a cat in `accent`, outlines, white patches, eyes and nose keep their color
+pub fn tint(art: &str, accent: (u8, u8, u8)) -> String {
+    color::recolor(art, |(r, g, b)| {
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        if max - min > 40 || max < 60 || min > 200 {
+            return (r, g, b);
+        }
+
+        let lightness = (r as f64 + g as f64 + b as f64) / 3.0 / FUR_LIGHTNESS;
+        let shade = |channel: u8| (channel as f64 * lightness).round().min(255.0) as u8;
+        (shade(accent.0), shade(accent.1), shade(accent.2))
+    })
+}
@@ -0,0 +1,183 @@
+use super::convert::{self, Image, ALPHA_THRESHOLD};
+use crate::config::ImageProtocol;
+use crate::tty;
+use base64::Engine;
+use std::collections::BTreeMap;
+use std::env;
+use std::path::Path;
+
+// Cell size assumed when the terminal does not report one
+const DEFAULT_CELL: (u32, u32) = (10, 20);
+
+// Largest base64 chunk the kitty protocol accepts per escape sequence
+const KITTY_CHUNK: usize = 4096;
+
+/// Terminal graphics protocols an image logo can be drawn with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Protocol {
+    Kitty,
+    Sixel,
+}
+
+/// An image logo ready to print, and the cells it covers
+pub struct ImageLogo {
+    pub columns: usize,
+    pub rows: usize,
+    pub escape: String,
+}
+
+/// Protocol to draw image logos with, `None` means half blocks
+pub fn detect(preference: ImageProtocol) -> Option<Protocol> {
+    match preference {
+        ImageProtocol::Blocks => return None,
+        ImageProtocol::Kitty => return Some(Protocol::Kitty),
+        ImageProtocol::Sixel => return Some(Protocol::Sixel),
+        ImageProtocol::Auto => {}
+    }
+
+    // Terminals known to speak the kitty protocol, no need to ask them. tmux and
+    // screen pass these variables on from the outer terminal but do not pass
+    // the graphics through, so inside them only the query below counts.
+    let term = env::var("TERM").unwrap_or_default();
+    let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
+    let multiplexer = env::var_os("TMUX").is_some()
+        || env::var_os("STY").is_some()
+        || term.starts_with("screen")
+        || term.starts_with("tmux");
+    if !multiplexer
+        && (env::var_os("KITTY_WINDOW_ID").is_some()
+            || term == "xterm-kitty"
+            || term == "xterm-ghostty"
+            || term_program == "WezTerm"
+            || term_program == "ghostty")
+    {
+        return Some(Protocol::Kitty);
+    }
+
+    // Ask about a 1x1 kitty image; sixel support shows up as `4` among the device attributes
+    let response = tty::query("\x1b_Gi=31,s=1,v=1,a=q,t=d,f=24;AAAA\x1b\\")?;
+    if response.contains("\x1b_Gi=31;OK") {
+        return Some(Protocol::Kitty);
+    }
+    let attributes = response.rsplit("\x1b[?").next()?;
+    let attributes = attributes.split('c').next()?;
+    if attributes.split(';').any(|attribute| attribute == "4") {
+        return Some(Protocol::Sixel);
+    }
+    None
+}
+
+/// Scale the image at `path` to `columns` cells wide and encode it for `protocol`
+pub fn render(path: &Path, columns: usize, protocol: Protocol) -> Result<ImageLogo, String> {
+    let image = convert::load(path)?;
+    if image.width == 0 || image.height == 0 {
+        return Err("the image is empty".to_string());
+    }
+
+    // Keep the aspect ratio, the rows are whatever height that takes
+    let (cell_width, cell_height) = tty::cell_size().unwrap_or(DEFAULT_CELL);
+    let (cell_width, cell_height) = (cell_width as usize, cell_height as usize);
+    let width = columns * cell_width;
+    let height = (image.height * width / image.width).max(1);
+    let rows = height.div_ceil(cell_height);
+
+    let scaled = convert::resize(&image, width, height);
+    let escape = match protocol {
+        Protocol::Kitty => kitty(&scaled, columns, rows),
+        Protocol::Sixel => sixel(&scaled),
+    };
+    Ok(ImageLogo {
+        columns,
+        rows,
+        escape,
+    })
+}
+
+// Raw RGBA in base64 chunks, placed over `columns` x `rows` cells without moving the cursor
+fn kitty(image: &Image, columns: usize, rows: usize) -> String {
+    let rgba: Vec<u8> = image.pixels.iter().flatten().copied().collect();
+    let payload = base64::engine::general_purpose::STANDARD.encode(rgba);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK).collect();
+
+    let mut escape = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = (i + 1 < chunks.len()) as u8;
+        let chunk = String::from_utf8_lossy(chunk);
+        if i == 0 {
+            escape.push_str(&format!(
+                "\x1b_Ga=T,f=32,s={},v={},c={},r={},C=1,q=2,m={};{}\x1b\\",
+                image.width, image.height, columns, rows, more, chunk
+            ));
+        } else {
+            escape.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+        }
+    }
+    escape
+}
+
+// Six pixel rows per band, colors reduced to the 6x6x6 cube, transparent pixels left unpainted
+fn sixel(image: &Image) -> String {
+    let level = |channel: u8| (channel as usize * 5 + 127) / 255;
+    let register = |pixel: [u8; 4]| -> Option<usize> {
+        (pixel[3] as u32 >= ALPHA_THRESHOLD)
+            .then(|| level(pixel[0]) * 36 + level(pixel[1]) * 6 + level(pixel[2]))
+    };
+
+    let mut escape = format!("\x1bP0;1;0q\"1;1;{};{}", image.width, image.height);
+    // Registers are defined in percent
+    let mut used = vec![false; 216];
+    for pixel in &image.pixels {
+        if let Some(register) = register(*pixel) {
+            used[register] = true;
+        }
+    }
+    for (register, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+        let percent = |level: usize| level * 100 / 5;
+        escape.push_str(&format!(
+            "#{};2;{};{};{}",
+            register,
+            percent(register / 36),
+            percent(register / 6 % 6),
+            percent(register % 6)
+        ));
+    }
+
+    for band in (0..image.height).step_by(6) {
+        // Bit pattern of every column, per color present in this band
+        let mut columns: BTreeMap<usize, Vec<u8>> = BTreeMap::new();
+        for row in 0..6.min(image.height - band) {
+            for x in 0..image.width {
+                if let Some(register) = register(image.pixel(x, band + row)) {
+                    columns
+                        .entry(register)
+                        .or_insert_with(|| vec![0; image.width])[x] |= 1 << row;
+                }
+            }
+        }
+
+        for (register, bits) in columns {
+            escape.push_str(&format!("#{}", register));
+            push_run_length(&mut escape, &bits);
+            // Back to the start of the band for the next color
+            escape.push('$');
+        }
+        escape.push('-');
+    }
+    escape.push_str("\x1b\\");
+    escape
+}
+
+// Sixel characters, repeats written as `!<count><char>`
+fn push_run_length(escape: &mut String, bits: &[u8]) {
+    let mut i = 0;
+    while i < bits.len() {
+        let run = bits[i..].iter().take_while(|b| **b == bits[i]).count();
+        let sixel = (63 + bits[i]) as char;
+        if run > 3 {
+            escape.push_str(&format!("!{}{}", run, sixel));
+        } else {
+            (0..run).for_each(|_| escape.push(sixel));
+        }
+        i += run;
+    }
+}
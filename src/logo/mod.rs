@@ -0,0 +1,207 @@
+mod builtin;
+mod convert;
+mod distro;
+mod graphics;
+
+pub use convert::convert;
+pub use graphics::{detect, render, ImageLogo};
+
+use crate::config::{self, LogoConfig};
+use crate::info;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn parse_escape_sequences(input: &str) -> String {
+    // Replace common escape sequences with their actual control characters
+    input
+        .replace("\\x1b", "\x1b") // ESC (Escape)
+        .replace("\\n", "\n") // Newline
+        .replace("\\t", "\t") // Tab
+        .replace("\\r", "\r") // Carriage return
+        .replace("\\\"", "\"") // Double quote
+        .replace("\\'", "'") // Single quote
+        .replace("\\\\", "\\") // Backslash
+}
+
+// `~/.config/meowfetch/logos`, every `<name>.txt` in it is a logo called `<name>`
+fn logos_dir() -> Option<PathBuf> {
+    Some(config::config_dir()?.join("logos"))
+}
+
+/// Read a logo file in the `.meowrc` format, `None` when missing or empty
+pub fn load_file(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    if content.trim().is_empty() {
+        return None;
+    }
+    Some(parse_escape_sequences(&content))
+}
+
+/// How many built-in cats there are, `-t` takes 1 to this
+pub const BUILTIN_COUNT: usize = builtin::BUILTIN.len();
+
+/// The built-in cat `-t <number>` refers to
+pub fn builtin(number: usize) -> Option<String> {
+    let (_, art) = builtin::BUILTIN.get(number.checked_sub(1)?)?;
+    Some(art.to_string())
+}
+
+/// A logo by name, the logos directory comes first so a built-in cat can be replaced
+pub fn find(name: &str) -> Option<String> {
+    if let Some(dir) = logos_dir() {
+        if let Some(logo) = load_file(&dir.join(format!("{}.txt", name))) {
+            return Some(logo);
+        }
+    }
+    let (_, art) = builtin::BUILTIN
+        .iter()
+        .find(|(builtin, _)| *builtin == name)?;
+    Some(art.to_string())
+}
+
+/// Every logo by name for `--list-logos`, the built-in cats first
+pub fn library() -> Vec<(String, String)> {
+    let mut logos: Vec<(String, String)> = builtin::BUILTIN
+        .iter()
+        .map(|(name, art)| (name.to_string(), art.to_string()))
+        .collect();
+
+    let mut files: Vec<PathBuf> = match logos_dir().and_then(|dir| fs::read_dir(dir).ok()) {
+        Some(entries) => entries.flatten().map(|entry| entry.path()).collect(),
+        None => Vec::new(),
+    };
+    files.sort();
+    for path in files {
+        if path.extension().and_then(|extension| extension.to_str()) != Some("txt") {
+            continue;
+        }
+        let (name, logo) = match (path.file_stem(), load_file(&path)) {
+            (Some(name), Some(logo)) => (name.to_string_lossy().to_string(), logo),
+            _ => continue,
+        };
+        match logos.iter_mut().find(|(existing, _)| *existing == name) {
+            Some(entry) => entry.1 = logo,
+            None => logos.push((name, logo)),
+        }
+    }
+    logos
+}
+
+// `--logo` takes a path to a logo file as well as a name
+fn resolve(value: &str) -> Option<String> {
+    let path = config::expand_home(Path::new(value));
+    if path.is_file() {
+        return load_file(&path);
+    }
+    find(value)
+}
+
+/// Why a logo that was asked for is not shown, the next candidate is used instead
+#[derive(Debug)]
+pub enum LogoError {
+    /// Neither a logo file nor a logo of that name
+    NotFound(String),
+    /// `-t` or `type` beyond the built-in cats
+    NoBuiltin(usize),
+    /// `distro` names a distribution without colors
+    UnknownDistro(String),
+    /// A logo `path` that cannot be read
+    Unreadable(PathBuf),
+    /// An `image` that cannot be converted
+    Image(PathBuf, String),
+}
+
+impl fmt::Display for LogoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LogoError::NotFound(name) => write!(f, "no logo named `{}`", name),
+            LogoError::NoBuiltin(number) => write!(f, "there is no built-in cat {}", number),
+            LogoError::UnknownDistro(name) => write!(f, "no colors for distribution `{}`", name),
+            LogoError::Unreadable(path) => write!(f, "cannot read logo {}", path.display()),
+            LogoError::Image(path, err) => write!(f, "cannot show {}: {}", path.display(), err),
+        }
+    }
+}
+
+/// Pick the text logo: command line first, then the config (including the
+/// legacy `.meowrc`), then the default cat in the colors of the distribution.
+/// Whatever was asked for but skipped comes back next to the logo.
+pub fn select(
+    logo: Option<&str>,
+    cat_type: Option<usize>,
+    config: &LogoConfig,
+) -> (String, Vec<LogoError>) {
+    let mut errors = Vec::new();
+    if let Some(logo) = logo {
+        match resolve(logo) {
+            Some(logo) => return (logo, errors),
+            None => errors.push(LogoError::NotFound(logo.to_string())),
+        }
+    }
+    if let Some(number) = cat_type {
+        match builtin(number) {
+            Some(logo) => return (logo, errors),
+            None => errors.push(LogoError::NoBuiltin(number)),
+        }
+    }
+    let logo = match from_config(config, &mut errors) {
+        Some(logo) => logo,
+        None => default(config, &mut errors),
+    };
+    (logo, errors)
+}
+
+fn default(config: &LogoConfig, errors: &mut Vec<LogoError>) -> String {
+    let accent = match config.distro.as_deref() {
+        Some("none") => None,
+        Some(name) => {
+            let accent = distro::accent([name]);
+            if accent.is_none() {
+                errors.push(LogoError::UnknownDistro(name.to_string()));
+            }
+            accent
+        }
+        None => {
+            let os = info::os();
+            distro::accent(os.id.iter().chain(&os.id_like).map(String::as_str))
+        }
+    };
+    match accent {
+        Some(accent) => distro::tint(builtin::CAT_ART_1, accent),
+        None => builtin::CAT_ART_1.to_string(),
+    }
+}
+
+fn from_config(config: &LogoConfig, errors: &mut Vec<LogoError>) -> Option<String> {
+    // Image logos that cannot be drawn as graphics are converted to half blocks
+    if let Some(image) = &config.image {
+        let image = config::expand_home(image);
+        match convert(&image, config.width) {
+            Ok(logo) => return Some(parse_escape_sequences(&logo)),
+            Err(err) => errors.push(LogoError::Image(image, err)),
+        }
+    }
+    if let Some(path) = &config.path {
+        let path = config::expand_home(path);
+        match load_file(&path) {
+            Some(logo) => return Some(logo),
+            None => errors.push(LogoError::Unreadable(path)),
+        }
+    }
+    if let Some(name) = &config.name {
+        match find(name) {
+            Some(logo) => return Some(logo),
+            None => errors.push(LogoError::NotFound(name.clone())),
+        }
+    }
+    if let Some(number) = config.kind {
+        match builtin(number) {
+            Some(logo) => return Some(logo),
+            None => errors.push(LogoError::NoBuiltin(number)),
+        }
+    }
+
+    // The legacy logo file
+    load_file(&dirs::home_dir()?.join(".config").join(".meowrc"))
+}
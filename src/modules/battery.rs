@@ -0,0 +1,82 @@
+use super::{percentage_color, Context, Module};
+use crate::info::{self, BatteryInfo};
+use colored::*;
+
+/// Charge, status and health of every battery plus the AC adapter state
+pub struct Bat;
+
+impl Module for Bat {
+    fn name(&self) -> &str {
+        "bat"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let power = info::power();
+        let ac = match power.ac_online {
+            Some(true) => Some("AC connected"),
+            Some(false) => Some("AC disconnected"),
+            None => None,
+        };
+
+        // Desktops have no battery
+        if power.batteries.is_empty() {
+            return None;
+        }
+
+        let lines = power
+            .batteries
+            .iter()
+            .map(|battery| {
+                let label = if power.batteries.len() > 1 {
+                    format!("bat ({})", battery.name)
+                } else {
+                    "bat".to_string()
+                };
+                let mut details = battery_details(ctx, battery);
+                details.extend(ac.map(str::to_string));
+                format!("{}: {}", ctx.label(&label), details.join(", "))
+            })
+            .collect();
+        Some(lines)
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+fn battery_details(ctx: &Context, battery: &BatteryInfo) -> Vec<String> {
+    let mut details = Vec::new();
+
+    // A low charge is the alarming case, so the usage colors are inverted
+    let capacity = match battery.capacity {
+        Some(capacity) => format!(
+            "{}{}",
+            ctx.bar("bat", capacity, true),
+            format!("{:.0}%", capacity).color(percentage_color(100.0 - capacity))
+        ),
+        None => "unknown".to_string(),
+    };
+    match &battery.status {
+        Some(status) => details.push(format!("{} ({})", capacity, status.to_lowercase())),
+        None => details.push(capacity),
+    }
+
+    if let Some(seconds) = battery.seconds_remaining {
+        let direction = match battery.status.as_deref() {
+            Some("Charging") => "until full",
+            _ => "left",
+        };
+        details.push(format!(
+            "{}h {:02}m {}",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            direction
+        ));
+    }
+    if let Some(health) = battery.health {
+        details.push(format!("health {:.0}%", health));
+    }
+
+    details
+}
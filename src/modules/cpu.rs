@@ -0,0 +1,86 @@
+use super::{colorize_percentage, percentage_color, Context, Module};
+use crate::info::{self, CpuInfo};
+use colored::*;
+
+/// CPU brand, cores, frequency and usage
+pub struct Cpu;
+
+/// 1, 5 and 15 minute load averages per core
+pub struct Load;
+
+impl Module for Cpu {
+    fn name(&self) -> &str {
+        "cpu"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let cpu = info::cpu(ctx.sys);
+        let mut line = format!("{}: {}", ctx.label("cpu"), cpu.brand);
+        if let Some(cores) = format_cores(&cpu) {
+            line.push_str(&format!(" ({})", cores));
+        }
+        if let Some(frequency) = format_frequency(&cpu) {
+            line.push_str(&format!(" @ {}", frequency));
+        }
+        line.push_str(&format!(
+            " {}[{}]",
+            ctx.bar("cpu", cpu.usage_percentage, false),
+            colorize_percentage(cpu.usage_percentage)
+        ));
+        Some(vec![line])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+impl Module for Load {
+    fn name(&self) -> &str {
+        "load"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let load = info::load();
+        let cores = ctx.sys.cpus().len().max(1) as f64;
+
+        // A load of 1.00 per core means every core was busy
+        let averages = [load.one, load.five, load.fifteen]
+            .iter()
+            .map(|average| {
+                let per_core = average / cores;
+                format!("{:.2}", per_core)
+                    .color(percentage_color(per_core * 100.0))
+                    .to_string()
+            })
+            .collect::<Vec<String>>();
+        Some(vec![format!(
+            "{}: {} per core",
+            ctx.label("load"),
+            averages.join(", ")
+        )])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+// `8C/16T`, or just the thread count when physical cores are unknown
+fn format_cores(cpu: &CpuInfo) -> Option<String> {
+    match (cpu.physical_cores, cpu.logical_cores) {
+        (_, 0) => None,
+        (Some(physical), logical) => Some(format!("{}C/{}T", physical, logical)),
+        (None, logical) => Some(format!("{}T", logical)),
+    }
+}
+
+// `2.40/4.90 GHz`, current over max
+fn format_frequency(cpu: &CpuInfo) -> Option<String> {
+    let ghz = |mhz: u64| mhz as f64 / 1000.0;
+    match (cpu.frequency_mhz, cpu.max_frequency_mhz) {
+        (0, _) => None,
+        (current, Some(max)) => Some(format!("{:.2}/{:.2} GHz", ghz(current), ghz(max))),
+        (current, None) => Some(format!("{:.2} GHz", ghz(current))),
+    }
+}
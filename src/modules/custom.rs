@@ -0,0 +1,48 @@
+use super::{Context, Module};
+use crate::config::CustomConfig;
+use crate::info;
+use colored::*;
+
+/// A `[[custom]]` line from the config, showing what its command prints
+pub struct Custom {
+    name: String,
+    label: String,
+}
+
+impl Custom {
+    pub fn new(config: &CustomConfig) -> Custom {
+        Custom {
+            name: config.name.clone(),
+            label: config.label().to_string(),
+        }
+    }
+}
+
+impl Module for Custom {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let config = ctx
+            .config
+            .custom
+            .iter()
+            .find(|custom| custom.name == self.name)?;
+        let output = info::custom_output(config)?;
+        let output = match config.color() {
+            Some(color) => output.color(color).to_string(),
+            None => output,
+        };
+        Some(vec![format!("{}: {}", ctx.label(&self.label), output)])
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    // The ttl keeps slow commands from running on every redraw
+    fn volatile(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,43 @@
+use super::{colorize_percentage, Context, Module};
+use crate::{info, redact, units};
+
+/// One line per mounted disk
+pub struct Disk;
+
+impl Module for Disk {
+    fn name(&self) -> &str {
+        "disk"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let mut lines = Vec::new();
+
+        let mut disks = info::disks(ctx.disks, &ctx.config.disk);
+        if ctx.redact {
+            redact::disks(&mut disks);
+        }
+
+        for disk in &disks {
+            let Some(percentage) = disk.used_percentage() else {
+                continue;
+            };
+            let prefix = format!("disk ({})", disk.mount_point);
+
+            lines.push(format!(
+                "{}: {}{} >> {} ({}) - {}",
+                ctx.label(&prefix),
+                ctx.bar("disk", percentage, false),
+                units::format_bytes(disk.used_bytes(), &ctx.config.units),
+                units::format_bytes(disk.total_bytes, &ctx.config.units),
+                colorize_percentage(percentage),
+                disk.file_system
+            ));
+        }
+
+        (!lines.is_empty()).then_some(lines)
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,48 @@
+use super::{Context, Module};
+use crate::info;
+
+/// One line per display adapter
+pub struct Gpu;
+
+impl Module for Gpu {
+    fn name(&self) -> &str {
+        "gpu"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let gpus = info::gpus();
+        if gpus.is_empty() {
+            return None;
+        }
+
+        let lines = gpus
+            .iter()
+            .map(|gpu| {
+                let vendor = match &gpu.vendor {
+                    Some(vendor) => short_name(vendor),
+                    None => format!("vendor {}", gpu.vendor_id),
+                };
+                let model = match &gpu.model {
+                    Some(model) => short_name(model),
+                    None => format!("device {}", gpu.device_id),
+                };
+                let driver = gpu.driver.as_deref().unwrap_or("no driver");
+                format!("{}: {} {} ({})", ctx.label("gpu"), vendor, model, driver)
+            })
+            .collect();
+        Some(lines)
+    }
+}
+
+// pci.ids puts the marketing name in brackets, e.g. `GA104 [GeForce RTX 3070]`
+// or `Advanced Micro Devices, Inc. [AMD/ATI]`, which is what people recognize
+fn short_name(name: &str) -> String {
+    if let (Some(start), Some(end)) = (name.find('['), name.rfind(']')) {
+        if start < end {
+            return name[start + 1..end].to_string();
+        }
+    }
+    name.trim_end_matches(" Corporation")
+        .trim_end_matches(", Inc.")
+        .to_string()
+}
@@ -0,0 +1,16 @@
+use super::{Context, Module};
+use crate::info;
+
+/// Kernel release, e.g. `6.1.0-18-amd64`
+pub struct Kernel;
+
+impl Module for Kernel {
+    fn name(&self) -> &str {
+        "kernel"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let kernel = info::kernel()?;
+        Some(vec![format!("{}: {}", ctx.label("kernel"), kernel)])
+    }
+}
@@ -0,0 +1,50 @@
+use super::{colorize_percentage, Context, Module};
+use crate::info::{self, MemoryInfo};
+use crate::units;
+
+/// Used and total RAM
+pub struct Mem;
+
+/// Used and total swap space
+pub struct Swap;
+
+impl Module for Mem {
+    fn name(&self) -> &str {
+        "mem"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        format_memory(ctx, "mem", &info::memory(ctx.sys)).map(|line| vec![line])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+impl Module for Swap {
+    fn name(&self) -> &str {
+        "swap"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        format_memory(ctx, "swap", &info::swap(ctx.sys)).map(|line| vec![line])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+// Nothing to show without any memory, which is how machines without swap report it
+fn format_memory(ctx: &Context, name: &str, memory: &MemoryInfo) -> Option<String> {
+    let percentage = memory.used_percentage()?;
+    Some(format!(
+        "{}: {}{} >> {} ({})",
+        ctx.label(name),
+        ctx.bar(name, percentage, false),
+        units::format_bytes(memory.used_bytes, &ctx.config.units),
+        units::format_bytes(memory.total_bytes, &ctx.config.units),
+        colorize_percentage(percentage)
+    ))
+}
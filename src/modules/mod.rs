@@ -0,0 +1,206 @@
+mod battery;
+mod cpu;
+mod custom;
+mod disk;
+mod gpu;
+mod kernel;
+mod memory;
+mod network;
+mod packages;
+mod palette;
+mod shell;
+mod sys;
+mod temp;
+mod title;
+mod uptime;
+
+use crate::config::{Config, Unavailable};
+use crate::info::Sources;
+use crate::width;
+use colored::*;
+use sysinfo::{Components, Disks, Networks, System};
+
+/// Everything a module can read while rendering its lines
+pub struct Context<'a> {
+    pub config: &'a Config,
+    pub sys: &'a System,
+    pub disks: &'a Disks,
+    pub networks: &'a Networks,
+    pub components: &'a Components,
+    /// Replace identifying values with placeholders (`--redact`)
+    pub redact: bool,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(config: &'a Config, sources: &'a Sources, redact: bool) -> Context<'a> {
+        Context {
+            config,
+            sys: &sources.sys,
+            disks: &sources.disks,
+            networks: &sources.networks,
+            components: &sources.components,
+            redact,
+        }
+    }
+
+    /// Format the label every info line starts with, padded to the usual width
+    pub fn label(&self, name: &str) -> ColoredString {
+        let padding = 4usize.saturating_sub(width::display_width(name));
+        format!("{}{}", name, " ".repeat(padding)).color(self.config.colors.label_color())
+    }
+
+    /// `[██████░░░░] ` to put in front of a ratio, empty unless `[bar] modules` lists `module`.
+    /// `inverted` is for ratios where a low value is the bad one, like battery charge.
+    pub fn bar(&self, module: &str, percentage: f64, inverted: bool) -> String {
+        let config = &self.config.bar;
+        if !config.modules.iter().any(|name| name == module) || !percentage.is_finite() {
+            return String::new();
+        }
+
+        let colors = config.colors();
+        let color = |percentage: f64| {
+            let percentage = if inverted {
+                100.0 - percentage
+            } else {
+                percentage
+            };
+            colors[usage_level(percentage)]
+        };
+        let percentage = percentage.clamp(0.0, 100.0);
+        let filled = (percentage / 100.0 * config.width as f64).round() as usize;
+
+        let mut bar = String::from("[");
+        for cell in 0..config.width {
+            if cell >= filled {
+                bar.push_str(&config.empty);
+                continue;
+            }
+            // With a gradient every cell is colored by where it sits on the scale
+            let at = if config.gradient {
+                (cell as f64 + 0.5) / config.width as f64 * 100.0
+            } else {
+                percentage
+            };
+            bar.push_str(&config.fill.color(color(at)).to_string());
+        }
+        bar.push_str("] ");
+        bar
+    }
+}
+
+/// One named entry of the info column, e.g. `cpu` or `disk`
+pub trait Module {
+    /// Name used in `modules.order` and `--modules`
+    fn name(&self) -> &str;
+
+    /// Lines to print, a module may produce several (one per disk). `None` when
+    /// there is nothing to report on this machine, e.g. no swap or no battery.
+    fn render(&self, ctx: &Context) -> Option<Vec<String>>;
+
+    /// Label of the `none` line that stands in when there is nothing to report
+    fn label(&self) -> &str {
+        self.name()
+    }
+
+    /// Whether the lines change while meowfetch runs, `--watch` only re-renders these
+    fn volatile(&self) -> bool {
+        false
+    }
+}
+
+/// Every built-in module
+pub fn builtin() -> Vec<Box<dyn Module>> {
+    vec![
+        Box::new(title::Title),
+        Box::new(sys::Sys),
+        Box::new(kernel::Kernel),
+        Box::new(uptime::Uptime),
+        Box::new(packages::Pkgs),
+        Box::new(shell::Shell),
+        Box::new(shell::Term),
+        Box::new(cpu::Cpu),
+        Box::new(cpu::Load),
+        Box::new(temp::Temp),
+        Box::new(gpu::Gpu),
+        Box::new(memory::Mem),
+        Box::new(memory::Swap),
+        Box::new(network::Ipv4),
+        Box::new(network::Ipv6),
+        Box::new(disk::Disk),
+        Box::new(battery::Bat),
+        Box::new(palette::Palette),
+    ]
+}
+
+/// Look modules up by name, keeping the requested order. Built-in modules come
+/// first, then the `[[custom]]` ones from the config. Names that match neither
+/// are returned next to the modules.
+pub fn select(names: &[String], config: &Config) -> (Vec<Box<dyn Module>>, Vec<String>) {
+    let mut selected = Vec::new();
+    let mut unknown = Vec::new();
+    for name in names {
+        let module = builtin()
+            .into_iter()
+            .find(|module| module.name() == name)
+            .or_else(|| {
+                let custom = config.custom.iter().find(|custom| custom.name == *name)?;
+                Some(Box::new(custom::Custom::new(custom)) as Box<dyn Module>)
+            });
+        match module {
+            Some(module) => selected.push(module),
+            None => unknown.push(name.clone()),
+        }
+    }
+    (selected, unknown)
+}
+
+/// Names of `[[custom]]` modules that a built-in module of the same name hides
+pub fn shadowed(config: &Config) -> Vec<&str> {
+    let builtin = builtin();
+    config
+        .custom
+        .iter()
+        .map(|custom| custom.name.as_str())
+        .filter(|name| builtin.iter().any(|module| module.name() == *name))
+        .collect()
+}
+
+/// The lines of a module, `modules.unavailable` decides what stands in for missing data
+pub fn render(module: &dyn Module, ctx: &Context) -> Vec<String> {
+    match module.render(ctx) {
+        Some(lines) => lines,
+        None => match ctx.config.modules.unavailable {
+            Unavailable::Hide => Vec::new(),
+            Unavailable::None => vec![format!("{}: none", ctx.label(module.label()))],
+        },
+    }
+}
+
+/// The lines of all modules, one after another
+pub fn lines(modules: &[Box<dyn Module>], ctx: &Context) -> Vec<String> {
+    modules
+        .iter()
+        .flat_map(|module| render(module.as_ref(), ctx))
+        .collect()
+}
+
+// Which third of the usage scale a percentage is in: plenty left, filling up or nearly full
+fn usage_level(percentage: f64) -> usize {
+    if percentage < 50.0 {
+        0
+    } else if (50.0..90.0).contains(&percentage) {
+        1
+    } else {
+        2
+    }
+}
+
+/// Color for a usage percentage, green while there is plenty left and red when nearly full
+pub fn percentage_color(percentage: f64) -> Color {
+    [Color::Green, Color::Yellow, Color::Red][usage_level(percentage)]
+}
+
+/// Function to colorize percentage based on value
+pub fn colorize_percentage(percentage: f64) -> ColoredString {
+    format!("{:.1}%", percentage).color(percentage_color(percentage))
+}
@@ -0,0 +1,98 @@
+use super::{Context, Module};
+use crate::info::{self, NetInfo, Scope};
+use crate::redact;
+use colored::*;
+
+/// IPv4 addresses of every interface
+pub struct Ipv4;
+
+/// IPv6 addresses by scope: global, unique-local, and link-local on request
+pub struct Ipv6;
+
+impl Module for Ipv4 {
+    fn name(&self) -> &str {
+        "ipv4"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let networks: Vec<String> = addresses(ctx)
+            .iter()
+            .filter(|net| !net.ipv6)
+            .map(entry)
+            .collect();
+        if networks.is_empty() {
+            return None;
+        }
+        Some(vec![format!(
+            "{}: {}",
+            ctx.label("ipv4"),
+            networks.join(", ")
+        )])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+impl Module for Ipv6 {
+    fn name(&self) -> &str {
+        "ipv6"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        // Read the interfaces once and split them up by scope
+        let networks = addresses(ctx);
+        let scope = |scope: Scope| -> Vec<String> {
+            networks
+                .iter()
+                .filter(|net| net.ipv6 && net.scope == scope)
+                .map(entry)
+                .collect()
+        };
+
+        // Global addresses come unlabeled, loopback is left out
+        let mut groups = Vec::new();
+        let global = scope(Scope::Global);
+        if !global.is_empty() {
+            groups.push(global.join(", "));
+        }
+        let unique_local = scope(Scope::UniqueLocal);
+        if !unique_local.is_empty() {
+            groups.push(format!("unique-local: {}", unique_local.join(", ")));
+        }
+        if ctx.config.network.link_local {
+            let link_local = scope(Scope::LinkLocal);
+            if !link_local.is_empty() {
+                groups.push(format!("link-local: {}", link_local.join(", ")));
+            }
+        }
+
+        if groups.is_empty() {
+            return None;
+        }
+        Some(vec![format!(
+            "{}: {}",
+            ctx.label("ipv6"),
+            groups.join(" | ")
+        )])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+// Every address the config lets through, redacted if asked to
+fn addresses(ctx: &Context) -> Vec<NetInfo> {
+    let mut networks = info::networks(ctx.networks, &ctx.config.network);
+    if ctx.redact {
+        redact::networks(&mut networks);
+    }
+    networks
+}
+
+// `address/prefix (interface)`
+fn entry(net: &NetInfo) -> String {
+    format!("{}/{} ({})", net.address, net.prefix, net.interface.cyan())
+}
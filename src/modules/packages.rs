@@ -0,0 +1,26 @@
+use super::{Context, Module};
+use crate::info;
+
+/// Installed packages per package manager
+pub struct Pkgs;
+
+impl Module for Pkgs {
+    fn name(&self) -> &str {
+        "pkgs"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let counts: Vec<String> = info::packages()
+            .iter()
+            .map(|package| format!("{} ({})", package.count, package.manager))
+            .collect();
+        if counts.is_empty() {
+            return None;
+        }
+        Some(vec![format!(
+            "{}: {}",
+            ctx.label("pkgs"),
+            counts.join(", ")
+        )])
+    }
+}
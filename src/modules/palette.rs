@@ -0,0 +1,128 @@
+use super::{Context, Module};
+use crate::config::PaletteRow;
+use crate::info;
+use colored::*;
+
+/// Color blocks to check the terminal colorscheme with
+pub struct Palette;
+
+const BRIGHT: [Color; 8] = [
+    Color::BrightRed,
+    Color::BrightYellow,
+    Color::BrightGreen,
+    Color::BrightCyan,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightBlack,
+    Color::BrightWhite,
+];
+const DARK: [Color; 8] = [
+    Color::Red,
+    Color::Yellow,
+    Color::Green,
+    Color::Cyan,
+    Color::Blue,
+    Color::Magenta,
+    Color::Black,
+    Color::White,
+];
+
+impl Module for Palette {
+    fn name(&self) -> &str {
+        "palette"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let config = &ctx.config.palette;
+        let block = config.glyph.repeat(config.width);
+
+        let mut lines = Vec::new();
+        for row in &config.rows {
+            match row {
+                PaletteRow::Bright => lines.push(blocks(&block, &BRIGHT)),
+                PaletteRow::Dark => lines.push(blocks(&block, &DARK)),
+                PaletteRow::Ansi16 => {
+                    let row = (0..16).map(|index| ansi256(&block, index)).collect();
+                    lines.push(row);
+                }
+                PaletteRow::Ansi256 => {
+                    // Six slices of the color cube, then the 24 grays
+                    for red in 0..6 {
+                        let row = (0..36)
+                            .map(|index| ansi256(&config.glyph, 16 + red * 36 + index))
+                            .collect();
+                        lines.push(row);
+                    }
+                    let grays = (232..=255).map(|index| ansi256(&config.glyph, index));
+                    lines.push(grays.collect());
+                }
+                PaletteRow::Truecolor => {
+                    // As wide as the 8 color rows
+                    let steps = (8 * config.width).max(8);
+                    let row = (0..steps)
+                        .map(|step| {
+                            let (r, g, b) = hue(step as f64 / steps as f64);
+                            config.glyph.truecolor(r, g, b).to_string()
+                        })
+                        .collect();
+                    lines.push(row);
+                }
+                PaletteRow::Theme => lines.extend(theme()),
+            }
+        }
+        Some(lines)
+    }
+}
+
+fn blocks(block: &str, colors: &[Color]) -> String {
+    colors
+        .iter()
+        .map(|color| block.color(*color).to_string())
+        .collect()
+}
+
+// `colored` has no 256 color support, so the escape is written by hand
+fn ansi256(text: &str, index: usize) -> String {
+    if colored::control::SHOULD_COLORIZE.should_colorize() {
+        format!("\x1b[38;5;{}m{}\x1b[0m", index, text)
+    } else {
+        text.to_string()
+    }
+}
+
+// Fully saturated color at `position` (0..1) around the hue circle
+fn hue(position: f64) -> (u8, u8, u8) {
+    let sector = position * 6.0;
+    let rising = ((sector % 1.0) * 255.0) as u8;
+    let falling = 255 - rising;
+    match sector as usize {
+        0 => (255, rising, 0),
+        1 => (falling, 255, 0),
+        2 => (0, 255, rising),
+        3 => (0, falling, 255),
+        4 => (rising, 0, 255),
+        _ => (255, 0, falling),
+    }
+}
+
+// `#rrggbb` of the 16 theme colors, normal ones first, each drawn in its own color
+fn theme() -> Vec<String> {
+    let colors = match info::theme_colors() {
+        Some(colors) => colors,
+        None => return vec!["theme colors unavailable".to_string()],
+    };
+    colors
+        .chunks(8)
+        .map(|row| {
+            row.iter()
+                .map(|color| match color {
+                    Some((r, g, b)) => format!("#{:02x}{:02x}{:02x}", r, g, b)
+                        .truecolor(*r, *g, *b)
+                        .to_string(),
+                    None => "   ?   ".to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect()
+}
@@ -0,0 +1,34 @@
+use super::{Context, Module};
+use crate::info;
+
+/// Login shell and its version
+pub struct Shell;
+
+/// Terminal emulator meowfetch is running in
+pub struct Term;
+
+impl Module for Shell {
+    fn name(&self) -> &str {
+        "shell"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let shell = info::shell()?;
+        let value = match shell.version {
+            Some(version) => format!("{} {}", shell.name, version),
+            None => shell.name,
+        };
+        Some(vec![format!("{}: {}", ctx.label("shell"), value)])
+    }
+}
+
+impl Module for Term {
+    fn name(&self) -> &str {
+        "term"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let terminal = info::terminal(ctx.sys)?;
+        Some(vec![format!("{}: {}", ctx.label("term"), terminal)])
+    }
+}
@@ -0,0 +1,21 @@
+use super::{Context, Module};
+use crate::info;
+
+/// Operating system name and version
+pub struct Sys;
+
+impl Module for Sys {
+    fn name(&self) -> &str {
+        "sys"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let os = info::os();
+        Some(vec![format!(
+            "{}: {} {}",
+            ctx.label("sys"),
+            os.name,
+            os.version
+        )])
+    }
+}
@@ -0,0 +1,43 @@
+use super::{percentage_color, Context, Module};
+use crate::info;
+use colored::*;
+
+/// CPU, GPU and NVMe temperatures
+pub struct Temp;
+
+impl Module for Temp {
+    fn name(&self) -> &str {
+        "temp"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        let sensors = info::temperatures(ctx.components, &ctx.config.temp);
+        // Most VMs have no readable sensors
+        if sensors.is_empty() {
+            return None;
+        }
+
+        let readings = sensors
+            .iter()
+            .map(|sensor| {
+                // Colored by how close the sensor is to its critical temperature
+                let critical = sensor
+                    .critical_celsius
+                    .filter(|critical| *critical > 0.0)
+                    .unwrap_or(ctx.config.temp.critical);
+                let celsius = format!("{:.0}°C", sensor.celsius)
+                    .color(percentage_color(sensor.celsius / critical * 100.0));
+                format!("{} {}", sensor.label, celsius)
+            })
+            .collect::<Vec<String>>();
+        Some(vec![format!(
+            "{}: {}",
+            ctx.label("temp"),
+            readings.join(", ")
+        )])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
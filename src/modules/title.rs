@@ -0,0 +1,34 @@
+use super::{Context, Module};
+use crate::{info, redact, width};
+use colored::*;
+
+/// `user@host` followed by a separator line of the same length
+pub struct Title;
+
+impl Module for Title {
+    fn name(&self) -> &str {
+        "title"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        // Minimal containers may have no host name at all
+        let user_info = if ctx.redact {
+            format!("{}@{}", redact::USER, redact::HOST)
+        } else {
+            match info::host_name() {
+                Some(host) => format!("{}@{}", whoami::username(), host),
+                None => whoami::username(),
+            }
+        };
+        let separator_width = width::display_width(&ctx.config.layout.separator).max(1);
+        let separator = ctx
+            .config
+            .layout
+            .separator
+            .repeat(width::display_width(&user_info) / separator_width);
+        Some(vec![
+            user_info.color(ctx.config.colors.title_color()).to_string(),
+            separator,
+        ])
+    }
+}
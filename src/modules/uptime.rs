@@ -0,0 +1,48 @@
+use super::{Context, Module};
+use crate::info;
+
+/// Time since boot
+pub struct Uptime;
+
+impl Module for Uptime {
+    fn name(&self) -> &str {
+        "uptime"
+    }
+
+    fn render(&self, ctx: &Context) -> Option<Vec<String>> {
+        Some(vec![format!(
+            "{}: {}",
+            ctx.label("uptime"),
+            format_uptime(info::uptime())
+        )])
+    }
+
+    fn volatile(&self) -> bool {
+        true
+    }
+}
+
+// `2 days, 3 hours, 14 mins`, leading zero units are left out
+fn format_uptime(seconds: u64) -> String {
+    let days = seconds / 86400;
+    let hours = seconds % 86400 / 3600;
+    let minutes = seconds % 3600 / 60;
+
+    let mut parts = Vec::new();
+    if days > 0 {
+        parts.push(plural(days, "day"));
+    }
+    if days > 0 || hours > 0 {
+        parts.push(plural(hours, "hour"));
+    }
+    parts.push(plural(minutes, "min"));
+    parts.join(", ")
+}
+
+fn plural(count: u64, unit: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, unit)
+    } else {
+        format!("{} {}s", count, unit)
+    }
+}
@@ -0,0 +1,83 @@
+/// Shell style glob matching for names and paths in the config
+///
+/// `*` matches any run of characters (including `/`), `?` matches exactly one.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    // Classic backtracking matcher, remembering the last `*` to retry from
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// True if any of the patterns matches
+pub fn any_match(patterns: &[String], text: &str) -> bool {
+    patterns.iter().any(|pattern| glob_match(pattern, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_literals_and_question_marks() {
+        assert!(glob_match("eth0", "eth0"));
+        assert!(!glob_match("eth0", "eth1"));
+        assert!(glob_match("eth?", "eth1"));
+        assert!(!glob_match("eth?", "eth"));
+        assert!(!glob_match("eth?", "eth10"));
+        assert!(glob_match("??", "日本"));
+    }
+
+    #[test]
+    fn matches_stars() {
+        assert!(glob_match("veth*", "veth1a2b"));
+        assert!(glob_match("veth*", "veth"));
+        assert!(!glob_match("veth*", "eth0"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("/snap/*", "/snap/core/123"));
+        assert!(glob_match("*loop*", "/dev/loop0"));
+        assert!(glob_match("**", "anything"));
+    }
+
+    #[test]
+    fn empty_pattern_matches_only_empty_text() {
+        assert!(glob_match("", ""));
+        assert!(!glob_match("", "eth0"));
+        assert!(!glob_match("eth0", ""));
+    }
+
+    #[test]
+    fn backtracks_to_the_last_star() {
+        assert!(glob_match("a*b*c", "aXbYbc"));
+        assert!(glob_match("a*b*c", "abc"));
+        assert!(!glob_match("a*b*c", "aXbYbcd"));
+        assert!(glob_match("*a?c", "abcaxc"));
+        assert!(!glob_match("a*b", "aXbY"));
+        assert!(glob_match("*.*.*", "a.b.c.d"));
+    }
+
+    #[test]
+    fn any_match_needs_one_pattern() {
+        let patterns = vec!["lo".to_string(), "docker*".to_string()];
+        assert!(any_match(&patterns, "docker0"));
+        assert!(!any_match(&patterns, "eth0"));
+        assert!(!any_match(&[], "eth0"));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::info::{DiskInfo, FetchInfo, NetInfo, Scope, ShellInfo};
+use std::path::Path;
+
+// Placeholders for `--redact`, so screenshots can be posted without leaking
+// names or addresses. Addresses come from the ranges reserved for
+// documentation (RFC 5737 and RFC 3849) and are numbered in listing order,
+// IPv4 and IPv6 separately, which keeps them stable between runs.
+//
+// What `[[custom]]` commands print is up to the user and is left alone.
+
+pub const USER: &str = "user";
+pub const HOST: &str = "host";
+
+/// Loopback addresses are the same everywhere and stay as they are
+pub fn networks(networks: &mut [NetInfo]) {
+    let mut ipv4 = 0;
+    let (mut ipv6, mut link_local) = (0u16, 0u16);
+    for net in networks.iter_mut() {
+        net.address = match (net.ipv6, net.scope) {
+            (_, Scope::Loopback) => continue,
+            // Link-local addresses are usually derived from the MAC address
+            (true, Scope::LinkLocal) => {
+                link_local = link_local.wrapping_add(1);
+                format!("fe80::{:x}", link_local)
+            }
+            (true, _) => {
+                ipv6 = ipv6.wrapping_add(1);
+                format!("2001:db8::{:x}", ipv6)
+            }
+            (false, _) => {
+                ipv4 += 1;
+                ipv4_placeholder(ipv4)
+            }
+        };
+    }
+}
+
+// The `n`th address of the three IPv4 documentation ranges, starting over after the last
+fn ipv4_placeholder(n: usize) -> String {
+    const RANGES: [&str; 3] = ["192.0.2", "198.51.100", "203.0.113"];
+    let index = (n - 1) % (RANGES.len() * 254);
+    format!("{}.{}", RANGES[index / 254], index % 254 + 1)
+}
+
+/// A shell installed in the home directory has the user name in its path
+pub fn shell(shell: &mut ShellInfo) {
+    let Some(home) = dirs::home_dir() else {
+        return;
+    };
+    if let Ok(rest) = Path::new(&shell.path).strip_prefix(home) {
+        let path = Path::new("/home").join(USER).join(rest);
+        shell.path = path.to_string_lossy().into_owned();
+    }
+}
+
+/// The root mount tells nothing about the machine, every other path might.
+/// Device paths are replaced too, LVM names often contain the host name.
+pub fn disks(disks: &mut [DiskInfo]) {
+    let (mut mounts, mut devices) = (0, 0);
+    for disk in disks.iter_mut() {
+        if disk.mount_point != "/" {
+            mounts += 1;
+            disk.mount_point = format!("/mnt/disk{}", mounts);
+        }
+        // Pseudo file systems are named like `tmpfs` instead of a device path
+        if disk.device.starts_with('/') {
+            devices += 1;
+            disk.device = format!("/dev/disk{}", devices);
+        }
+    }
+}
+
+pub fn fetch(fetch: &mut FetchInfo) {
+    fetch.user = USER.to_string();
+    if fetch.host.is_some() {
+        fetch.host = Some(HOST.to_string());
+    }
+    if let Some(info) = &mut fetch.shell {
+        shell(info);
+    }
+    networks(&mut fetch.networks);
+    disks(&mut fetch.disks);
+}
@@ -0,0 +1,79 @@
+use crate::color::{self, ColorLevel};
+use crate::logo::ImageLogo;
+use crate::width;
+
+/// Lay out the logo and info lines side by side
+pub fn format_fetch(logo: &str, lines: &[String], gap: usize) -> String {
+    // Split the logo and info into lines
+    let logo_lines: Vec<&str> = logo.lines().collect();
+    let info_lines: Vec<&str> = lines.iter().flat_map(|line| line.lines()).collect();
+
+    // Determine the maximum number of lines
+    let max_lines = std::cmp::max(logo_lines.len(), info_lines.len());
+
+    // Calculate the maximum width of the logo lines (based on visible characters)
+    let logo_width = logo_lines
+        .iter()
+        .map(|line| width::display_width(line))
+        .max()
+        .unwrap_or(0);
+
+    // Calculate the number of padding lines needed to center the logo vertically
+    let logo_padding = if logo_lines.len() < max_lines {
+        (max_lines - logo_lines.len()) / 2
+    } else {
+        0
+    };
+
+    // Put the logo and info lines side by side
+    let mut output = String::new();
+    for i in 0..max_lines {
+        // Determine the logo line to print (centered vertically)
+        let logo_line = if i >= logo_padding && i < logo_padding + logo_lines.len() {
+            logo_lines[i - logo_padding]
+        } else {
+            ""
+        };
+
+        // Get the corresponding info line
+        let info_line = info_lines.get(i).unwrap_or(&"");
+
+        // Calculate the visible width of the logo line
+        let visible_logo_width = width::display_width(logo_line);
+
+        // Calculate the padding needed to align the info line
+        let padding = logo_width.saturating_sub(visible_logo_width) + gap;
+
+        // Join the logo line and info line with correct padding
+        output.push_str(logo_line); // logo_line with color
+        output.push_str(&" ".repeat(padding)); // padding with spaces
+        output.push_str(info_line);
+        output.push('\n');
+    }
+    output
+}
+
+/// Print the fetch with its colors brought down to what the terminal supports
+pub fn render_fetch(logo: &str, lines: &[String], gap: usize, level: ColorLevel) {
+    print!(
+        "{}",
+        color::downgrade(&format_fetch(logo, lines, gap), level)
+    );
+}
+
+/// Print the fetch next to an image logo drawn with a graphics protocol
+///
+/// The text goes out first with blank cells where the logo belongs, then the
+/// cursor goes back up to draw the image into them. Doing it in that order
+/// means the terminal has already scrolled and the image lands in place.
+pub fn render_image_fetch(image: &ImageLogo, lines: &[String], gap: usize, level: ColorLevel) {
+    let placeholder = vec![" ".repeat(image.columns); image.rows].join("\n");
+    let text = format_fetch(&placeholder, lines, gap);
+
+    // Same vertical centering as `format_fetch`
+    let lines = text.lines().count();
+    let top = lines.saturating_sub(image.rows) / 2;
+
+    print!("{}", color::downgrade(&text, level));
+    print!("\x1b7\x1b[{}A\r{}\x1b8", lines - top, image.escape);
+}
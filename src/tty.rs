@@ -0,0 +1,97 @@
+// Talking to the terminal needs termios and ioctl, elsewhere it never answers
+#[cfg(unix)]
+pub use unix::{cell_size, query};
+
+#[cfg(not(unix))]
+pub fn query(_request: &str) -> Option<String> {
+    None
+}
+
+#[cfg(not(unix))]
+pub fn cell_size() -> Option<(u32, u32)> {
+    None
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::fs::{File, OpenOptions};
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsRawFd;
+    use std::time::{Duration, Instant};
+
+    // Terminals that do not answer at all are given up on after this
+    const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+
+    /// Send `request` to the controlling terminal and collect what it answers
+    ///
+    /// A device attributes query (`ESC [ c`) is sent last: every terminal answers
+    /// it, so its reply marks the end of the answers to everything before.
+    pub fn query(request: &str) -> Option<String> {
+        let mut tty = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .ok()?;
+        let fd = tty.as_raw_fd();
+
+        // Raw mode, so the replies are neither echoed nor held back until a newline
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+            return None;
+        }
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+        raw.c_cc[libc::VMIN] = 0;
+        raw.c_cc[libc::VTIME] = 1;
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return None;
+        }
+
+        let response = exchange(&mut tty, request);
+        unsafe { libc::tcsetattr(fd, libc::TCSANOW, &original) };
+        response
+    }
+
+    fn exchange(tty: &mut File, request: &str) -> Option<String> {
+        tty.write_all(request.as_bytes()).ok()?;
+        tty.write_all(b"\x1b[c").ok()?;
+        tty.flush().ok()?;
+
+        let started = Instant::now();
+        let mut response = Vec::new();
+        let mut buffer = [0u8; 256];
+        while started.elapsed() < QUERY_TIMEOUT {
+            match tty.read(&mut buffer) {
+                Ok(read) => response.extend_from_slice(&buffer[..read]),
+                Err(_) => break,
+            }
+            if let Some(start) = find(&response, b"\x1b[?") {
+                if response[start..].contains(&b'c') {
+                    break;
+                }
+            }
+        }
+        Some(String::from_utf8_lossy(&response).into_owned())
+    }
+
+    fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        haystack
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    /// Size of one character cell in pixels, when the terminal reports it
+    pub fn cell_size() -> Option<(u32, u32)> {
+        let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+        if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } != 0 {
+            return None;
+        }
+        if size.ws_col == 0 || size.ws_row == 0 || size.ws_xpixel == 0 || size.ws_ypixel == 0 {
+            return None;
+        }
+        Some((
+            (size.ws_xpixel / size.ws_col) as u32,
+            (size.ws_ypixel / size.ws_row) as u32,
+        ))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::config::{UnitSystem, UnitsConfig};
+
+const IEC: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+const SI: [&str; 6] = ["B", "kB", "MB", "GB", "TB", "PB"];
+
+/// A byte count as text, e.g. `512.00 MiB` or `1.50 TB`
+///
+/// Scaled to the largest unit that keeps the value at 1 or above, unless the
+/// config names one fixed unit. Plain bytes never get decimals.
+pub fn format_bytes(bytes: u64, config: &UnitsConfig) -> String {
+    let (system, fixed) = match find_unit(&config.unit) {
+        Some((system, exponent)) => (system, Some(exponent)),
+        None => (config.system, None),
+    };
+    let (base, names) = match system {
+        UnitSystem::Iec => (1024.0, IEC),
+        UnitSystem::Si => (1000.0, SI),
+    };
+
+    // Compared as printed, so 1 MiB minus a byte is `1.00 MiB` rather than `1024.00 KiB`
+    let scale = f64::powi(10.0, config.precision.min(15) as i32);
+    let printed = |value: f64| (value * scale).round() / scale;
+    let exponent = fixed.unwrap_or_else(|| {
+        let mut exponent = 0;
+        let mut value = bytes as f64;
+        while printed(value) >= base && exponent < names.len() - 1 {
+            value /= base;
+            exponent += 1;
+        }
+        exponent
+    });
+
+    let value = bytes as f64 / f64::powi(base, exponent as i32);
+    let precision = if exponent == 0 { 0 } else { config.precision };
+    format!("{:.precision$} {}", value, names[exponent])
+}
+
+// The system and power of a unit name such as "GiB" or "mb", a fixed unit
+// brings its own system along
+fn find_unit(unit: &str) -> Option<(UnitSystem, usize)> {
+    let find = |names: [&str; 6]| {
+        names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(unit))
+    };
+    find(IEC)
+        .map(|exponent| (UnitSystem::Iec, exponent))
+        .or_else(|| find(SI).map(|exponent| (UnitSystem::Si, exponent)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn units(system: UnitSystem, unit: &str, precision: usize) -> UnitsConfig {
+        UnitsConfig {
+            system,
+            unit: unit.to_string(),
+            precision,
+        }
+    }
+
+    #[test]
+    fn scales_small_values_down() {
+        let iec = UnitsConfig::default();
+        assert_eq!(format_bytes(512 * 1024 * 1024, &iec), "512.00 MiB");
+        assert_eq!(format_bytes(0, &iec), "0 B");
+        assert_eq!(format_bytes(1023, &iec), "1023 B");
+    }
+
+    #[test]
+    fn switches_units_at_the_iec_boundary() {
+        let iec = UnitsConfig::default();
+        assert_eq!(format_bytes(1024, &iec), "1.00 KiB");
+        assert_eq!(format_bytes(1024 * 1024 - 1, &iec), "1.00 MiB");
+        assert_eq!(format_bytes(1024 * 1024 - 10_000, &iec), "1014.23 KiB");
+        assert_eq!(format_bytes(1 << 30, &iec), "1.00 GiB");
+        assert_eq!(format_bytes(3 << 40, &iec), "3.00 TiB");
+    }
+
+    #[test]
+    fn switches_units_at_the_si_boundary() {
+        let si = units(UnitSystem::Si, "auto", 2);
+        assert_eq!(format_bytes(999, &si), "999 B");
+        assert_eq!(format_bytes(1000, &si), "1.00 kB");
+        assert_eq!(format_bytes(1024, &si), "1.02 kB");
+        assert_eq!(format_bytes(1_500_000_000_000, &si), "1.50 TB");
+    }
+
+    #[test]
+    fn fixed_units_bring_their_own_system() {
+        let iec = UnitsConfig::default();
+        let gib = units(UnitSystem::Iec, "GiB", 2);
+        let mb = units(UnitSystem::Iec, "mb", 1);
+        assert_eq!(format_bytes(512 * 1024 * 1024, &gib), "0.50 GiB");
+        assert_eq!(format_bytes(2_500_000, &mb), "2.5 MB");
+        assert_eq!(
+            format_bytes(1 << 30, &units(UnitSystem::Si, "B", 2)),
+            "1073741824 B"
+        );
+        // Unknown names fall back to scaling
+        assert_eq!(
+            format_bytes(1 << 20, &units(UnitSystem::Iec, "parsecs", 2)),
+            format_bytes(1 << 20, &iec)
+        );
+    }
+
+    #[test]
+    fn honors_precision() {
+        let whole = units(UnitSystem::Iec, "auto", 0);
+        assert_eq!(format_bytes(1536 * 1024 * 1024, &whole), "2 GiB");
+        assert_eq!(format_bytes(0, &whole), "0 B");
+    }
+}
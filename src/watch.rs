@@ -0,0 +1,74 @@
+use meowfetch::color::{self, ColorLevel};
+use meowfetch::config::Config;
+use meowfetch::info::Sources;
+use meowfetch::modules::{self, Context, Module};
+use meowfetch::render;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const ENTER_ALTERNATE_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+const LEAVE_ALTERNATE_SCREEN: &str = "\x1b[?25h\x1b[?1049l";
+const CURSOR_HOME: &str = "\x1b[H";
+const CLEAR_TO_LINE_END: &str = "\x1b[K";
+const CLEAR_TO_SCREEN_END: &str = "\x1b[J";
+
+// How often a sleeping watch loop checks for Ctrl-C
+const STOP_POLL: Duration = Duration::from_millis(50);
+
+// Redraw the fetch in place until interrupted
+//
+// Modules that never change are rendered once, the volatile ones (memory,
+// disks, addresses, ...) are rendered again after every refresh.
+pub fn run(
+    config: &Config,
+    mut sources: Sources,
+    logo: &str,
+    modules: &[Box<dyn Module>],
+    redact: bool,
+    interval: Duration,
+    level: ColorLevel,
+) {
+    let stop = Arc::new(AtomicBool::new(false));
+    let handler_stop = Arc::clone(&stop);
+    if let Err(err) = ctrlc::set_handler(move || handler_stop.store(true, Ordering::SeqCst)) {
+        eprintln!("meowfetch: cannot watch: {}", err);
+        return;
+    }
+
+    let mut stdout = io::stdout();
+    print!("{}", ENTER_ALTERNATE_SCREEN);
+
+    let mut cache: Vec<Option<Vec<String>>> = vec![None; modules.len()];
+    while !stop.load(Ordering::SeqCst) {
+        let started = Instant::now();
+        let ctx = Context::new(config, &sources, redact);
+
+        let mut lines = Vec::new();
+        for (module, cached) in modules.iter().zip(cache.iter_mut()) {
+            if module.volatile() {
+                lines.extend(modules::render(module.as_ref(), &ctx));
+            } else {
+                let render = || modules::render(module.as_ref(), &ctx);
+                lines.extend_from_slice(cached.get_or_insert_with(render));
+            }
+        }
+
+        // Clear what is left of longer lines from the previous frame
+        let frame = render::format_fetch(logo, &lines, config.layout.gap);
+        let frame = color::downgrade(&frame, level);
+        let frame = frame.replace('\n', &format!("{}\n", CLEAR_TO_LINE_END));
+        print!("{}{}{}", CURSOR_HOME, frame, CLEAR_TO_SCREEN_END);
+        let _ = stdout.flush();
+
+        while !stop.load(Ordering::SeqCst) && started.elapsed() < interval {
+            thread::sleep(STOP_POLL.min(interval.saturating_sub(started.elapsed())));
+        }
+        sources.refresh();
+    }
+
+    print!("{}", LEAVE_ALTERNATE_SCREEN);
+    let _ = stdout.flush();
+}
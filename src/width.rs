@@ -0,0 +1,102 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Remove terminal escape sequences, leaving only the printable text
+///
+/// Handles CSI (`ESC [ ... final`), OSC (`ESC ] ... BEL` or `ESC ] ... ESC \`)
+/// and the remaining two byte `ESC x` sequences.
+pub fn strip_ansi(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters and intermediates up to a final byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC, DCS, APC, PM, SOS: string terminated by BEL or ST
+            Some(']') | Some('P') | Some('_') | Some('^') | Some('X') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    result
+}
+
+/// Number of terminal cells `s` occupies once escape sequences are removed
+///
+/// Every grapheme cluster is measured as a whole so combining marks and emoji
+/// ZWJ sequences count once, East Asian wide characters take two cells.
+pub fn display_width(s: &str) -> usize {
+    strip_ansi(s)
+        .graphemes(true)
+        .map(|grapheme| grapheme.width().min(2))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_csi_sequences() {
+        assert_eq!(strip_ansi("\x1b[1;31mred\x1b[0m"), "red");
+        assert_eq!(strip_ansi("\x1b[38;2;255;128;0m▄\x1b[0m"), "▄");
+        assert_eq!(strip_ansi("a\x1b[2Kb"), "ab");
+    }
+
+    #[test]
+    fn strips_osc_sequences() {
+        // Hyperlink terminated by BEL, then by ST
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://x.org\x07link\x1b]8;;\x07"),
+            "link"
+        );
+        assert_eq!(strip_ansi("\x1b]0;title\x1b\\text"), "text");
+        // Kitty graphics are an APC string
+        assert_eq!(strip_ansi("\x1b_Ga=T;AAAA\x1b\\after"), "after");
+    }
+
+    #[test]
+    fn strips_two_byte_sequences() {
+        assert_eq!(strip_ansi("\x1b7saved\x1b8"), "saved");
+    }
+
+    #[test]
+    fn counts_cjk_as_two_cells() {
+        assert_eq!(display_width("猫"), 2);
+        assert_eq!(display_width("\x1b[32mねこ\x1b[0m cat"), 8);
+    }
+
+    #[test]
+    fn counts_zwj_emoji_once() {
+        assert_eq!(display_width("👨‍👩‍👧"), 2);
+        assert_eq!(display_width("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn measures_block_and_braille_logos() {
+        assert_eq!(display_width("\x1b[38;2;0;0;0m███\x1b[0m▀▄"), 5);
+        assert_eq!(display_width("⣿⣷⡄ ⢀"), 5);
+        assert_eq!(display_width("/\\_/\\"), 5);
+    }
+}